@@ -0,0 +1,311 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use time::{Date, Duration, OffsetDateTime, Time, UtcOffset};
+
+/// Zone in which times are displayed and baseball days are reckoned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeZone {
+    Utc,
+    /// Fixed offset from UTC, without daylight saving time.
+    Fixed(UtcOffset),
+    UsEastern,
+    UsCentral,
+    UsMountain,
+    /// Mountain time without daylight saving time.
+    UsArizona,
+    UsPacific,
+    UsAlaska,
+    /// Hawaii–Aleutian time without daylight saving time.
+    UsHawaii,
+}
+
+/// Date of the `n`th Sunday of a month.
+fn nth_sunday(year: i32, month: u8, n: u8) -> Date {
+    let first = Date::try_from_ymd(year, month, 1).expect("first of month is valid");
+    let until_sunday = (7 - first.weekday().number_days_from_sunday()) % 7;
+    first + Duration::days((until_sunday + 7 * (n - 1)) as i64)
+}
+
+/// Date of the last Sunday of a month.
+fn last_sunday(year: i32, month: u8) -> Date {
+    let fifth = nth_sunday(year, month, 5);
+    if fifth.month() == month {
+        fifth
+    } else {
+        fifth - Duration::days(7)
+    }
+}
+
+/// Dates on which US daylight saving time starts and ends, at 2am local time.
+///
+/// Rules before 1967 varied by state and are not modelled, so no daylight saving time applies.
+fn us_daylight_saving_dates(year: i32) -> Option<(Date, Date)> {
+    match year {
+        2007..=i32::MAX => Some((nth_sunday(year, 3, 2), nth_sunday(year, 11, 1))),
+        1987..=2006 => Some((nth_sunday(year, 4, 1), last_sunday(year, 10))),
+        1967..=1986 => Some((last_sunday(year, 4), last_sunday(year, 10))),
+        _ => None,
+    }
+}
+
+impl TimeZone {
+    /// Offset from UTC of a US zone outside daylight saving time.
+    fn us_standard_hours(self) -> Option<i8> {
+        match self {
+            TimeZone::UsEastern => Some(-5),
+            TimeZone::UsCentral => Some(-6),
+            TimeZone::UsMountain | TimeZone::UsArizona => Some(-7),
+            TimeZone::UsPacific => Some(-8),
+            TimeZone::UsAlaska => Some(-9),
+            TimeZone::UsHawaii => Some(-10),
+            _ => None,
+        }
+    }
+
+    /// Whether US daylight saving time is in effect, for zones observing it.
+    fn is_daylight_saving(self, datetime: OffsetDateTime) -> bool {
+        if let TimeZone::UsArizona | TimeZone::UsHawaii = self {
+            return false;
+        }
+        let standard = match self.us_standard_hours() {
+            Some(standard) => standard,
+            None => return false,
+        };
+        let year = datetime.to_offset(UtcOffset::hours(standard)).year();
+        let (starts, ends) = match us_daylight_saving_dates(year) {
+            Some(dates) => dates,
+            None => return false,
+        };
+        let starts = starts
+            .with_time(time::time!(2:00))
+            .assume_offset(UtcOffset::hours(standard));
+        let ends = ends
+            .with_time(time::time!(2:00))
+            .assume_offset(UtcOffset::hours(standard + 1));
+        starts <= datetime && datetime < ends
+    }
+
+    /// Offset from UTC at an instant.
+    pub fn offset_at(self, datetime: OffsetDateTime) -> UtcOffset {
+        match self {
+            TimeZone::Utc => UtcOffset::UTC,
+            TimeZone::Fixed(offset) => offset,
+            _ => {
+                let standard = self.us_standard_hours().unwrap_or_default();
+                if self.is_daylight_saving(datetime) {
+                    UtcOffset::hours(standard + 1)
+                } else {
+                    UtcOffset::hours(standard)
+                }
+            }
+        }
+    }
+
+    /// Convert an instant into this zone.
+    pub fn to_local(self, datetime: OffsetDateTime) -> OffsetDateTime {
+        datetime.to_offset(self.offset_at(datetime))
+    }
+
+    /// Abbreviation at an instant, e.g. "EDT" or "UTC+0530".
+    pub fn abbreviation(self, datetime: OffsetDateTime) -> String {
+        let letter = match self {
+            TimeZone::UsEastern => "E",
+            TimeZone::UsCentral => "C",
+            TimeZone::UsMountain | TimeZone::UsArizona => "M",
+            TimeZone::UsPacific => "P",
+            TimeZone::UsAlaska => "AK",
+            TimeZone::UsHawaii => "H",
+            TimeZone::Utc => return "UTC".to_string(),
+            TimeZone::Fixed(_) => {
+                return self.offset_at(datetime).format("UTC%z");
+            }
+        };
+        let season = if self.is_daylight_saving(datetime) {
+            "D"
+        } else {
+            "S"
+        };
+        format!("{}{}T", letter, season)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnknownTimeZone(pub String);
+
+impl fmt::Display for UnknownTimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown time zone {:?}", self.0)
+    }
+}
+
+impl Error for UnknownTimeZone {}
+
+impl FromStr for TimeZone {
+    type Err = UnknownTimeZone;
+
+    /// Parse a zone name such as "US/Pacific" or "America/New_York", "UTC", or a fixed offset
+    /// such as "+05:30".
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let zone = match name {
+            "UTC" | "utc" | "Z" => TimeZone::Utc,
+            "US/Eastern" | "America/New_York" => TimeZone::UsEastern,
+            "US/Central" | "America/Chicago" => TimeZone::UsCentral,
+            "US/Mountain" | "America/Denver" => TimeZone::UsMountain,
+            "US/Arizona" | "America/Phoenix" => TimeZone::UsArizona,
+            "US/Pacific" | "America/Los_Angeles" => TimeZone::UsPacific,
+            "US/Alaska" | "America/Anchorage" => TimeZone::UsAlaska,
+            "US/Hawaii" | "Pacific/Honolulu" => TimeZone::UsHawaii,
+            _ => {
+                let offset = UtcOffset::parse(name.replace(':', ""), "%z")
+                    .map_err(|_| UnknownTimeZone(name.to_string()))?;
+                TimeZone::Fixed(offset)
+            }
+        };
+        Ok(zone)
+    }
+}
+
+/// Rule deciding which date a moment belongs to.
+///
+/// Games running past midnight belong to the day they started on, so the day only rolls over
+/// once the last games have finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BaseballDay {
+    pub zone: TimeZone,
+    /// Local time at which the next day begins.
+    pub rollover: Time,
+}
+
+impl Default for BaseballDay {
+    /// Roll over at 6am US/Eastern.
+    fn default() -> Self {
+        BaseballDay {
+            zone: TimeZone::UsEastern,
+            rollover: time::time!(6:00),
+        }
+    }
+}
+
+impl BaseballDay {
+    pub fn new(zone: TimeZone, rollover: Time) -> Self {
+        BaseballDay { zone, rollover }
+    }
+
+    /// Baseball day an instant belongs to.
+    pub fn date_of(&self, datetime: OffsetDateTime) -> Date {
+        let local = self.zone.to_local(datetime);
+        if local.time() < self.rollover {
+            local.date().previous_day()
+        } else {
+            local.date()
+        }
+    }
+
+    /// Current baseball day.
+    pub fn today(&self) -> Date {
+        self.date_of(OffsetDateTime::now_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::{date, offset, time};
+
+    fn utc(date: Date, time: Time) -> OffsetDateTime {
+        date.with_time(time).assume_utc()
+    }
+
+    #[test]
+    fn us_daylight_saving() {
+        let zone = TimeZone::UsEastern;
+        // 2018 daylight saving time ran from March 11 until November 4
+        assert_eq!(
+            zone.offset_at(utc(date!(2018 - 03 - 11), time!(6:59))),
+            offset!(-5)
+        );
+        assert_eq!(
+            zone.offset_at(utc(date!(2018 - 03 - 11), time!(7:00))),
+            offset!(-4)
+        );
+        assert_eq!(
+            zone.offset_at(utc(date!(2018 - 11 - 04), time!(5:59))),
+            offset!(-4)
+        );
+        assert_eq!(
+            zone.offset_at(utc(date!(2018 - 11 - 04), time!(6:00))),
+            offset!(-5)
+        );
+        assert_eq!(
+            TimeZone::UsPacific.abbreviation(utc(date!(2018 - 06 - 10), time!(12:00))),
+            "PDT"
+        );
+        assert_eq!(
+            TimeZone::UsArizona.abbreviation(utc(date!(2018 - 06 - 10), time!(12:00))),
+            "MST"
+        );
+        assert_eq!(
+            TimeZone::UsHawaii.offset_at(utc(date!(2018 - 06 - 10), time!(12:00))),
+            offset!(-10)
+        );
+    }
+
+    #[test]
+    fn historical_daylight_saving() {
+        let zone = TimeZone::UsEastern;
+        // 2006 daylight saving time ran from April 2 until October 29
+        assert_eq!(
+            zone.offset_at(utc(date!(2006 - 03 - 20), time!(12:00))),
+            offset!(-5)
+        );
+        assert_eq!(
+            zone.offset_at(utc(date!(2006 - 04 - 02), time!(7:00))),
+            offset!(-4)
+        );
+        assert_eq!(
+            zone.offset_at(utc(date!(2006 - 10 - 29), time!(6:00))),
+            offset!(-5)
+        );
+        // 1986 daylight saving time began on the last Sunday of April
+        assert_eq!(
+            zone.offset_at(utc(date!(1986 - 04 - 20), time!(12:00))),
+            offset!(-5)
+        );
+        assert_eq!(
+            zone.offset_at(utc(date!(1986 - 04 - 27), time!(12:00))),
+            offset!(-4)
+        );
+    }
+
+    #[test]
+    fn late_game_stays_on_its_day() {
+        let day = BaseballDay::default();
+        // 10:10pm PDT on June 10th is 5:10am UTC on June 11th
+        assert_eq!(
+            day.date_of(utc(date!(2018 - 06 - 11), time!(5:10))),
+            date!(2018 - 06 - 10)
+        );
+        // 6am EDT
+        assert_eq!(
+            day.date_of(utc(date!(2018 - 06 - 11), time!(10:00))),
+            date!(2018 - 06 - 11)
+        );
+
+        let midnight = BaseballDay::new(TimeZone::Utc, time!(0:00));
+        assert_eq!(
+            midnight.date_of(utc(date!(2018 - 06 - 11), time!(5:10))),
+            date!(2018 - 06 - 11)
+        );
+    }
+
+    #[test]
+    fn parse_time_zone() {
+        assert_eq!("US/Pacific".parse(), Ok(TimeZone::UsPacific));
+        assert_eq!("UTC".parse(), Ok(TimeZone::Utc));
+        assert_eq!("+05:30".parse(), Ok(TimeZone::Fixed(offset!(+5:30))));
+        assert_eq!(
+            "Mars/Olympus".parse::<TimeZone>(),
+            Err(UnknownTimeZone("Mars/Olympus".to_string()))
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use std::{error, fmt};
+
+use reqwest::{StatusCode, Url};
+use time::Date;
+
+/// Errors returned by [`MlbClient`](crate::MlbClient) requests.
+#[derive(Debug)]
+pub enum MlbError {
+    /// The request could not be sent or the response could not be read.
+    Transport(reqwest::Error),
+    /// The API responded with a non-success status code.
+    Status { code: StatusCode, body: String },
+    /// The response body did not match the expected JSON structure.
+    Decode {
+        /// Path of the field which failed to decode, e.g. `dates[0].games[3].gamePk`.
+        path: String,
+        source: serde_json::Error,
+    },
+    /// The API returned no data for the requested date.
+    NoData { date: Date },
+    /// The API returned no person for the requested ID.
+    NoPerson { person_id: u32 },
+    /// The configured base URL cannot have endpoint paths joined onto it.
+    InvalidBaseUrl { url: Url },
+}
+
+impl fmt::Display for MlbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MlbError::Transport(err) => write!(f, "transport error: {}", err),
+            MlbError::Status { code, body } => write!(f, "unexpected status {}: {}", code, body),
+            MlbError::Decode { path, source } => {
+                write!(f, "failed to decode field `{}`: {}", path, source)
+            }
+            MlbError::NoData { date } => write!(f, "no data for {}", date.format("%Y-%m-%d")),
+            MlbError::NoPerson { person_id } => write!(f, "no person with id {}", person_id),
+            MlbError::InvalidBaseUrl { url } => write!(f, "{} cannot be used as a base URL", url),
+        }
+    }
+}
+
+impl error::Error for MlbError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            MlbError::Transport(err) => Some(err),
+            MlbError::Decode { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for MlbError {
+    fn from(err: reqwest::Error) -> Self {
+        MlbError::Transport(err)
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for MlbError {
+    fn from(err: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        MlbError::Decode {
+            path: err.path().to_string(),
+            source: err.into_inner(),
+        }
+    }
+}
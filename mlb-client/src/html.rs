@@ -0,0 +1,371 @@
+//! Conversion of editorial HTML bodies into rich text.
+//!
+//! Only the subset of HTML used by editorial articles is understood: block elements become
+//! paragraphs, `b`/`strong` and `i`/`em` become styled spans and `img` becomes an image reference.
+//! Other tags are dropped while keeping their text, except for scripts and styles.
+
+/// Run of text sharing a style.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+/// Image embedded in an article.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageRef {
+    pub src: String,
+    pub alt: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Inline {
+    Text(Span),
+    Image(ImageRef),
+}
+
+/// Paragraphs of styled text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RichText {
+    pub paragraphs: Vec<Vec<Inline>>,
+}
+
+impl RichText {
+    /// Text without styling, one line per paragraph.
+    pub fn plain_text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(|paragraph| {
+                paragraph
+                    .iter()
+                    .filter_map(|inline| match inline {
+                        Inline::Text(span) => Some(span.text.as_str()),
+                        Inline::Image(_) => None,
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+const BLOCK_TAGS: &[&str] = &[
+    "p",
+    "div",
+    "br",
+    "li",
+    "ul",
+    "ol",
+    "blockquote",
+    "figure",
+    "figcaption",
+    "section",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+];
+const HEADING_TAGS: &[&str] = &["h1", "h2", "h3", "h4", "h5", "h6"];
+const SKIPPED_TAGS: &[&str] = &["script", "style"];
+
+struct Builder {
+    rich_text: RichText,
+    paragraph: Vec<Inline>,
+    /// Depth of currently open bold and italic elements.
+    bold: u32,
+    italic: u32,
+    /// Whitespace was seen since the last text was pushed.
+    pending_space: bool,
+}
+
+impl Builder {
+    fn push_text(&mut self, text: &str) {
+        if text.starts_with(char::is_whitespace) {
+            self.pending_space = true;
+        }
+        for (i, word) in text.split_whitespace().enumerate() {
+            let needs_space = (i > 0 || self.pending_space) && !self.paragraph.is_empty();
+            let word = if needs_space {
+                format!(" {}", word)
+            } else {
+                word.to_string()
+            };
+            let (bold, italic) = (self.bold > 0, self.italic > 0);
+            match self.paragraph.last_mut() {
+                Some(Inline::Text(span)) if span.bold == bold && span.italic == italic => {
+                    span.text.push_str(&word)
+                }
+                _ => self.paragraph.push(Inline::Text(Span {
+                    text: word,
+                    bold,
+                    italic,
+                })),
+            }
+            self.pending_space = false;
+        }
+        if !text.is_empty() {
+            self.pending_space = text.ends_with(char::is_whitespace);
+        }
+    }
+
+    fn break_paragraph(&mut self) {
+        if !self.paragraph.is_empty() {
+            let paragraph = std::mem::take(&mut self.paragraph);
+            self.rich_text.paragraphs.push(paragraph);
+        }
+        self.pending_space = false;
+    }
+}
+
+/// Decode character references such as `&amp;` and `&#8217;`.
+fn decode_entities(raw: &str) -> String {
+    let mut decoded = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = match rest.find(';') {
+            Some(end) if end <= 10 => end,
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let name = &rest[1..end];
+        let character = match name {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            "lsquo" => Some('\u{2018}'),
+            "rsquo" => Some('\u{2019}'),
+            "ldquo" => Some('\u{201C}'),
+            "rdquo" => Some('\u{201D}'),
+            "ndash" => Some('\u{2013}'),
+            "mdash" => Some('\u{2014}'),
+            "hellip" => Some('\u{2026}'),
+            _ if name.starts_with("#x") || name.starts_with("#X") => {
+                u32::from_str_radix(&name[2..], 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
+            }
+            _ if name.starts_with('#') => name[1..].parse().ok().and_then(std::char::from_u32),
+            _ => None,
+        };
+        match character {
+            Some(character) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Value of an attribute within the inside of a tag, e.g. `img src="a.jpg" alt='A'`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut search = 0;
+    while let Some(found) = lower[search..].find(name) {
+        let start = search + found;
+        search = start + name.len();
+        // Must be a whole attribute name followed by `=`
+        let preceded = lower[..start].ends_with(char::is_whitespace);
+        let after = lower[search..].trim_start();
+        if !preceded || !after.starts_with('=') {
+            continue;
+        }
+        let value = tag[tag.len() - after.len() + 1..].trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ '"') | Some(quote @ '\'') => {
+                let value = &value[1..];
+                &value[..value.find(quote).unwrap_or(value.len())]
+            }
+            _ => &value[..value.find(char::is_whitespace).unwrap_or(value.len())],
+        };
+        return Some(decode_entities(value));
+    }
+    None
+}
+
+/// Start of the next tag, skipping `<` which cannot open one such as in "1 < 2".
+fn find_tag(html: &str) -> Option<usize> {
+    html.match_indices('<')
+        .map(|(start, _)| start)
+        .find(|start| {
+            matches!(
+                html[start + 1..].chars().next(),
+                Some(c) if c.is_ascii_alphabetic() || "/!?".contains(c)
+            )
+        })
+}
+
+/// Convert an HTML fragment into paragraphs of rich text.
+pub fn to_rich_text(html: &str) -> RichText {
+    let mut builder = Builder {
+        rich_text: RichText::default(),
+        paragraph: Vec::new(),
+        bold: 0,
+        italic: 0,
+        pending_space: false,
+    };
+    let mut skipping: Option<String> = None;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let (text, tag) = match find_tag(rest) {
+            Some(start) => {
+                let end = rest[start..].find('>').map(|end| start + end);
+                match end {
+                    Some(end) => (&rest[..start], Some((&rest[start + 1..end], end + 1))),
+                    // Unterminated tag, treat the remainder as text
+                    None => (rest, None),
+                }
+            }
+            None => (rest, None),
+        };
+
+        if skipping.is_none() {
+            builder.push_text(&decode_entities(text));
+        }
+        let (tag, consumed) = match tag {
+            Some(tag) => tag,
+            None => break,
+        };
+        rest = &rest[consumed..];
+
+        // Comments and doctypes
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        let closing = tag.starts_with('/');
+        let inner = tag.trim_start_matches('/').trim_end_matches('/');
+        let name = inner
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        if let Some(skipped) = &skipping {
+            if closing && name == *skipped {
+                skipping = None;
+            }
+            continue;
+        }
+        if SKIPPED_TAGS.contains(&name.as_str()) && !closing {
+            skipping = Some(name);
+            continue;
+        }
+
+        if BLOCK_TAGS.contains(&name.as_str()) {
+            builder.break_paragraph();
+        }
+        // Track nesting so that spans end with their outermost element
+        let nest = |depth: u32| {
+            if closing {
+                depth.saturating_sub(1)
+            } else {
+                depth + 1
+            }
+        };
+        match name.as_str() {
+            "b" | "strong" => builder.bold = nest(builder.bold),
+            _ if HEADING_TAGS.contains(&name.as_str()) => builder.bold = nest(builder.bold),
+            "i" | "em" => builder.italic = nest(builder.italic),
+            "img" if !closing => {
+                if let Some(src) = attribute(inner, "src") {
+                    builder.paragraph.push(Inline::Image(ImageRef {
+                        src,
+                        alt: attribute(inner, "alt").filter(|alt| !alt.is_empty()),
+                    }));
+                    builder.pending_space = true;
+                }
+            }
+            _ => {}
+        }
+    }
+    builder.break_paragraph();
+    builder.rich_text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str, bold: bool, italic: bool) -> Inline {
+        Inline::Text(Span {
+            text: text.to_string(),
+            bold,
+            italic,
+        })
+    }
+
+    #[test]
+    fn paragraphs_and_styles() {
+        let html = r#"<p>Aaron Judge <b>homered</b>   twice &amp; drove in
+            <strong>four <em>runs</em></strong>.</p>
+            <!-- note --><h3>Up next</h3><p>Sunday&#8217;s game<br>at 1:05&nbsp;p.m.</p>"#;
+        let rich_text = to_rich_text(html);
+
+        assert_eq!(
+            rich_text.paragraphs,
+            vec![
+                vec![
+                    text("Aaron Judge", false, false),
+                    text(" homered", true, false),
+                    text(" twice & drove in", false, false),
+                    text(" four", true, false),
+                    text(" runs", true, true),
+                    text(".", false, false),
+                ],
+                vec![text("Up next", true, false)],
+                vec![text("Sunday\u{2019}s game", false, false)],
+                vec![text("at 1:05 p.m.", false, false)],
+            ]
+        );
+    }
+
+    #[test]
+    fn images_and_skipped_content() {
+        let html = r#"<div class="image"><img src="https://img.mlbstatic.com/a.jpg" alt='Judge &amp; Stanton' /></div>
+            <script>var x = "<p>ignored</p>";</script>
+            <p>After <a href="/news">the game</a> <img data-x="1" src=b.png></p>"#;
+        let rich_text = to_rich_text(html);
+
+        assert_eq!(
+            rich_text.paragraphs,
+            vec![
+                vec![Inline::Image(ImageRef {
+                    src: "https://img.mlbstatic.com/a.jpg".to_string(),
+                    alt: Some("Judge & Stanton".to_string()),
+                })],
+                vec![
+                    text("After the game", false, false),
+                    Inline::Image(ImageRef {
+                        src: "b.png".to_string(),
+                        alt: None,
+                    }),
+                ],
+            ]
+        );
+        assert_eq!(rich_text.plain_text(), "\nAfter the game");
+    }
+
+    #[test]
+    fn malformed_input() {
+        let rich_text = to_rich_text("1 < 2 &bogus; <b>unclosed <i");
+
+        assert_eq!(rich_text.plain_text(), "1 < 2 &bogus; unclosed <i");
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node {
+    name: String,
+    children: Vec<Node>,
+}
+
+/// Builder for the `hydrate` query parameter, which requests additional nested data.
+///
+/// Paths sharing a prefix are merged, so requesting the editorial recap and preview renders as
+/// `game(content(editorial(recap,preview)))`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hydrate {
+    nodes: Vec<Node>,
+}
+
+impl Hydrate {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Request an arbitrary nested path, e.g. `&["venue", "location"]`.
+    pub fn path(mut self, path: &[&str]) -> Self {
+        let mut nodes = &mut self.nodes;
+        for name in path {
+            let index = match nodes.iter().position(|node| node.name == *name) {
+                Some(index) => index,
+                None => {
+                    nodes.push(Node {
+                        name: name.to_string(),
+                        children: Vec::new(),
+                    });
+                    nodes.len() - 1
+                }
+            };
+            nodes = &mut nodes[index].children;
+        }
+        self
+    }
+
+    /// Editorial recap article.
+    pub fn editorial(self) -> Self {
+        self.path(&["game", "content", "editorial", "recap"])
+    }
+
+    /// Editorial preview article, written before the game.
+    pub fn preview(self) -> Self {
+        self.path(&["game", "content", "editorial", "preview"])
+    }
+
+    /// Editorial wrap article, written after the game.
+    pub fn wrap(self) -> Self {
+        self.path(&["game", "content", "editorial", "wrap"])
+    }
+
+    /// Winning, losing and save pitchers.
+    pub fn decisions(self) -> Self {
+        self.path(&["decisions"])
+    }
+
+    /// Inning-by-inning line score.
+    pub fn linescore(self) -> Self {
+        self.path(&["linescore"])
+    }
+
+    /// Probable starting pitchers.
+    pub fn probable_pitcher(self) -> Self {
+        self.path(&["probablePitcher"])
+    }
+
+    /// Probable starting pitchers with their pitching statistics over a season.
+    pub fn probable_pitcher_stats(self, season: i32) -> Self {
+        let stats = format!("stats(group=[pitching],type=season,season={})", season);
+        self.path(&["probablePitcher", &stats])
+    }
+
+    /// TV and radio broadcasts.
+    pub fn broadcasts(self) -> Self {
+        self.path(&["broadcasts", "all"])
+    }
+
+    /// Weather at the venue.
+    pub fn weather(self) -> Self {
+        self.path(&["weather"])
+    }
+
+    /// Full team details.
+    pub fn team(self) -> Self {
+        self.path(&["team"])
+    }
+
+    /// Venue details including location and roof type.
+    pub fn venue(self) -> Self {
+        self.path(&["venue", "location"])
+            .path(&["venue", "fieldInfo"])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+fn fmt_nodes(nodes: &[Node], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, node) in nodes.iter().enumerate() {
+        if i != 0 {
+            write!(f, ",")?;
+        }
+        write!(f, "{}", node.name)?;
+        if !node.children.is_empty() {
+            write!(f, "(")?;
+            fmt_nodes(&node.children, f)?;
+            write!(f, ")")?;
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for Hydrate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_nodes(&self.nodes, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_default() {
+        let hydrate = Hydrate::new().editorial().decisions();
+
+        assert_eq!(
+            hydrate.to_string(),
+            "game(content(editorial(recap))),decisions"
+        );
+    }
+
+    #[test]
+    fn render_merged() {
+        let hydrate = Hydrate::new()
+            .editorial()
+            .preview()
+            .linescore()
+            .venue()
+            .path(&["venue", "timezone"])
+            .broadcasts();
+
+        assert_eq!(
+            hydrate.to_string(),
+            "game(content(editorial(recap,preview))),linescore,venue(location,fieldInfo,timezone),broadcasts(all)"
+        );
+    }
+
+    #[test]
+    fn render_probable_pitcher_stats() {
+        let hydrate = Hydrate::new().probable_pitcher_stats(2018);
+
+        assert_eq!(
+            hydrate.to_string(),
+            "probablePitcher(stats(group=[pitching],type=season,season=2018))"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Language editorial content is read in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    /// ISO 639-1 code, e.g. "es".
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    /// Language to fall back to when content is missing in this one.
+    pub fn fallback(self) -> Language {
+        match self {
+            Language::English => Language::Spanish,
+            Language::Spanish => Language::English,
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnknownLanguage(pub String);
+
+impl fmt::Display for UnknownLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown language {:?}", self.0)
+    }
+}
+
+impl Error for UnknownLanguage {}
+
+impl FromStr for Language {
+    type Err = UnknownLanguage;
+
+    /// Parse a language code or locale such as "es", "es-MX" or "en_US.UTF-8".
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let code = name
+            .split(&['-', '_', '.'][..])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match code.as_str() {
+            "en" | "english" => Ok(Language::English),
+            "es" | "spanish" | "español" => Ok(Language::Spanish),
+            _ => Err(UnknownLanguage(name.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_language() {
+        assert_eq!("es".parse(), Ok(Language::Spanish));
+        assert_eq!("es-MX".parse(), Ok(Language::Spanish));
+        assert_eq!("en_US.UTF-8".parse(), Ok(Language::English));
+        assert_eq!(
+            "fr".parse::<Language>(),
+            Err(UnknownLanguage("fr".to_string()))
+        );
+        assert_eq!(Language::Spanish.fallback(), Language::English);
+    }
+}
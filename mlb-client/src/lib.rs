@@ -1,18 +1,68 @@
+pub mod day;
+pub mod error;
+pub mod html;
+pub mod hydrate;
+pub mod language;
+pub mod live;
+pub mod query;
 pub mod types;
 
-use reqwest::{Client, Error, IntoUrl};
+#[cfg(test)]
+mod mock;
+
+use std::time::Duration;
+
+pub use reqwest::{header, Proxy, Url};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client, IntoUrl, RequestBuilder, Response,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
 pub use time::Date;
 
+pub use day::{BaseballDay, TimeZone};
+pub use error::MlbError;
+pub use hydrate::Hydrate;
+pub use language::Language;
+pub use live::{LiveFeedStream, LiveUpdate};
+pub use query::{GameType, LeaderCategory, ScheduleQuery, StatGroup, StatType};
 use types::*;
 
-const MLB_SCHEDULE_URL: &str = "http://statsapi.mlb.com/api/v1/schedule";
-const HYDRATE_ARGS: &str = "game(content(editorial(recap))),decisions";
+const DEFAULT_BASE_URL: &str = "http://statsapi.mlb.com/api/";
+const SCHEDULE_PATH: &str = "v1/schedule";
+const GAME_PATH: &str = "v1/game";
+const LIVE_GAME_PATH: &str = "v1.1/game";
+const STANDINGS_PATH: &str = "v1/standings";
+const TEAMS_PATH: &str = "v1/teams";
+const PEOPLE_PATH: &str = "v1/people";
+const LEADERS_PATH: &str = "v1/stats/leaders";
+const TRANSACTIONS_PATH: &str = "v1/transactions";
+const PERSON_HYDRATE: &str = "currentTeam,stats(group=[hitting,pitching],type=season)";
 const DATE_FORMAT: &str = "%Y-%m-%d";
+/// Longest range, in days, requested from the schedule endpoint at once.
+const MAX_RANGE_DAYS: i64 = 31;
+
+/// Decode an already parsed JSON value, tracking the path of any failing field.
+fn decode<T: DeserializeOwned>(value: Value) -> Result<T, MlbError> {
+    Ok(serde_path_to_error::deserialize(value)?)
+}
 
 /// Client providing HTTP requests to the mlb API.
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct MlbClient {
     client: Client,
+    base_url: Url,
+    baseball_day: BaseballDay,
+    language: Language,
+}
+
+impl Default for MlbClient {
+    fn default() -> Self {
+        MlbClientBuilder::new()
+            .build()
+            .expect("default client configuration is valid")
+    }
 }
 
 impl MlbClient {
@@ -20,29 +70,433 @@ impl MlbClient {
         Default::default()
     }
 
-    pub async fn get_schedule_via_date(&self, date: &Date) -> Result<Schedule, Error> {
-        let date_str = date.format(DATE_FORMAT);
-        let query_params = [
-            ("hydrate", HYDRATE_ARGS),
-            ("date", &date_str),
-            ("sportId", "1"),
-        ];
+    pub fn builder() -> MlbClientBuilder {
+        MlbClientBuilder::new()
+    }
+
+    /// The base URL every endpoint is resolved against.
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// The rule deciding which date is today.
+    pub fn baseball_day(&self) -> &BaseballDay {
+        &self.baseball_day
+    }
+
+    /// The preferred language of editorial content.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// A client sharing this one's connections and configuration, preferring `language`.
+    pub fn with_language(&self, language: Language) -> Self {
+        MlbClient {
+            language,
+            ..self.clone()
+        }
+    }
+
+    fn endpoint(&self, path: &str) -> Url {
+        // Base URL is checked to be a valid base with a trailing slash in the builder
+        self.base_url.join(path).expect("endpoint path is relative")
+    }
+
+    /// Send a request, rejecting non-success status codes.
+    async fn send(request: RequestBuilder) -> Result<Response, MlbError> {
+        let response = request.send().await?;
+        let code = response.status();
+        if code.is_success() {
+            Ok(response)
+        } else {
+            let body = response.text().await.unwrap_or_default();
+            Err(MlbError::Status { code, body })
+        }
+    }
+
+    /// Send a request and decode the JSON response, tracking the path of any failing field.
+    async fn get_json<T: DeserializeOwned>(request: RequestBuilder) -> Result<T, MlbError> {
+        let raw = Self::send(request).await?.bytes().await?;
+        let deserializer = &mut serde_json::Deserializer::from_slice(&raw);
+        Ok(serde_path_to_error::deserialize(deserializer)?)
+    }
+
+    /// Build a schedule request with the filters and hydrations of `query` applied.
+    fn schedule_request(&self, query: &ScheduleQuery) -> RequestBuilder {
         self.client
-            .get(MLB_SCHEDULE_URL)
-            .query(&query_params)
-            .send()
-            .await?
-            .json()
+            .get(self.endpoint(SCHEDULE_PATH))
+            .query(&query.to_params())
+    }
+
+    /// Fetch the schedule for a date.
+    ///
+    /// Returns [`MlbError::NoData`] when there are no games scheduled on the date.
+    pub async fn get_schedule_via_date(
+        &self,
+        date: &Date,
+        query: &ScheduleQuery,
+    ) -> Result<Schedule, MlbError> {
+        let date_str = date.format(DATE_FORMAT);
+        let request = self.schedule_request(query).query(&[("date", &date_str)]);
+        let schedule: Schedule = Self::get_json(request).await?;
+        if schedule.dates.is_empty() {
+            return Err(MlbError::NoData { date: *date });
+        }
+        Ok(schedule)
+    }
+
+    /// Fetch every date with games between `start` and `end` inclusive.
+    ///
+    /// Long ranges are split into several requests of at most a month each.
+    pub async fn get_schedule_range(
+        &self,
+        start: &Date,
+        end: &Date,
+        query: &ScheduleQuery,
+    ) -> Result<Vec<DateItem>, MlbError> {
+        let mut date_items = Vec::new();
+        let mut chunk_start = *start;
+        while chunk_start <= *end {
+            let chunk_end = (chunk_start + time::Duration::days(MAX_RANGE_DAYS - 1)).min(*end);
+            let start_str = chunk_start.format(DATE_FORMAT);
+            let end_str = chunk_end.format(DATE_FORMAT);
+            let request = self
+                .schedule_request(query)
+                .query(&[("startDate", &start_str), ("endDate", &end_str)]);
+            let schedule: Schedule = Self::get_json(request).await?;
+            date_items.extend(schedule.dates);
+
+            chunk_start = chunk_end.next_day();
+        }
+        Ok(date_items)
+    }
+
+    /// Fetch the inning-by-inning line score of a game.
+    pub async fn get_linescore(&self, game_pk: u32) -> Result<Linescore, MlbError> {
+        let path = format!("{}/{}/linescore", GAME_PATH, game_pk);
+        Self::get_json(self.client.get(self.endpoint(&path))).await
+    }
+
+    /// Fetch the batting and pitching lines of every player in a game.
+    pub async fn get_boxscore(&self, game_pk: u32) -> Result<Boxscore, MlbError> {
+        let path = format!("{}/{}/boxscore", GAME_PATH, game_pk);
+        Self::get_json(self.client.get(self.endpoint(&path))).await
+    }
+
+    /// Fetch the full live feed of a game.
+    pub async fn get_live_feed(&self, game_pk: u32) -> Result<LiveFeed, MlbError> {
+        decode(self.get_live_feed_raw(game_pk).await?)
+    }
+
+    pub(crate) async fn get_live_feed_raw(&self, game_pk: u32) -> Result<Value, MlbError> {
+        let path = format!("{}/{}/feed/live", LIVE_GAME_PATH, game_pk);
+        Self::get_json(self.client.get(self.endpoint(&path))).await
+    }
+
+    /// Fetch the changes to the live feed since `time_stamp`.
+    pub(crate) async fn get_live_feed_diff<T: DeserializeOwned>(
+        &self,
+        game_pk: u32,
+        time_stamp: &str,
+    ) -> Result<T, MlbError> {
+        let path = format!("{}/{}/feed/live/diffPatch", LIVE_GAME_PATH, game_pk);
+        let request = self
+            .client
+            .get(self.endpoint(&path))
+            .query(&[("startTimecode", time_stamp)]);
+        Self::get_json(request).await
+    }
+
+    /// Poll the live feed of a game for plays, score and inning changes until it ends.
+    ///
+    /// The feed's suggested wait is used between polls unless `poll_interval` is given.
+    pub fn live_feed_stream(
+        &self,
+        game_pk: u32,
+        poll_interval: Option<Duration>,
+    ) -> LiveFeedStream {
+        LiveFeedStream::new(self.clone(), game_pk, poll_interval)
+    }
+
+    /// Fetch regular season division standings of both leagues, as of `date` if given.
+    pub async fn get_standings(
+        &self,
+        season: i32,
+        date: Option<&Date>,
+    ) -> Result<Standings, MlbError> {
+        let league_ids = format!("{},{}", query::LEAGUE_AMERICAN, query::LEAGUE_NATIONAL);
+        let mut request = self.client.get(self.endpoint(STANDINGS_PATH)).query(&[
+            ("leagueId", league_ids.as_str()),
+            ("season", &season.to_string()),
+            ("standingsTypes", "regularSeason"),
+            ("hydrate", "team,division,league"),
+        ]);
+        if let Some(date) = date {
+            request = request.query(&[("date", date.format(DATE_FORMAT))]);
+        }
+        Self::get_json(request).await
+    }
+
+    /// Fetch the top `limit` MLB players of a season in a category.
+    pub async fn get_league_leaders(
+        &self,
+        category: LeaderCategory,
+        season: i32,
+        limit: u32,
+    ) -> Result<Vec<LeagueLeader>, MlbError> {
+        let request = self.client.get(self.endpoint(LEADERS_PATH)).query(&[
+            ("leaderCategories", category.as_str()),
+            ("statGroup", category.group().as_str()),
+            ("season", &season.to_string()),
+            ("limit", &limit.to_string()),
+            ("sportId", &query::SPORT_MLB.to_string()),
+        ]);
+        let response: LeagueLeaders = Self::get_json(request).await?;
+        Ok(response
+            .league_leaders
+            .into_iter()
+            .next()
+            .map(|category| category.leaders)
+            .unwrap_or_default())
+    }
+
+    /// Fetch the hitting, pitching and fielding totals of a team over a season.
+    pub async fn get_team_stats(&self, team_id: u32, season: i32) -> Result<Vec<Stats>, MlbError> {
+        let path = format!("{}/{}/stats", TEAMS_PATH, team_id);
+        let request = self.client.get(self.endpoint(&path)).query(&[
+            ("stats", StatType::Season.as_str()),
+            ("group", "hitting,pitching,fielding"),
+            ("season", &season.to_string()),
+        ]);
+        let response: StatsResponse = Self::get_json(request).await?;
+        Ok(response.stats)
+    }
+
+    /// Fetch transactions between two dates inclusive, optionally limited to one team.
+    pub async fn get_transactions(
+        &self,
+        start: &Date,
+        end: &Date,
+        team_id: Option<u32>,
+    ) -> Result<Vec<Transaction>, MlbError> {
+        let mut request = self.client.get(self.endpoint(TRANSACTIONS_PATH)).query(&[
+            ("startDate", start.format(DATE_FORMAT)),
+            ("endDate", end.format(DATE_FORMAT)),
+        ]);
+        if let Some(team_id) = team_id {
+            request = request.query(&[("teamId", team_id)]);
+        }
+        let response: Transactions = Self::get_json(request).await?;
+        Ok(response.transactions)
+    }
+
+    /// Fetch the active roster of a team on a date, with each player's profile and season
+    /// statistics.
+    pub async fn get_roster(
+        &self,
+        team_id: u32,
+        date: &Date,
+    ) -> Result<Vec<RosterEntry>, MlbError> {
+        let path = format!("{}/{}/roster", TEAMS_PATH, team_id);
+        let hydrate = format!(
+            "person(stats(group=[hitting,pitching],type=season,season={}))",
+            date.year()
+        );
+        let request = self.client.get(self.endpoint(&path)).query(&[
+            ("rosterType", "active"),
+            ("date", &date.format(DATE_FORMAT)),
+            ("hydrate", &hydrate),
+        ]);
+        let roster: Roster = Self::get_json(request).await?;
+        Ok(roster.roster)
+    }
+
+    /// Fetch the profile and current season statistics of a player.
+    pub async fn get_person(&self, person_id: u32) -> Result<PersonDetail, MlbError> {
+        let path = format!("{}/{}", PEOPLE_PATH, person_id);
+        let request = self
+            .client
+            .get(self.endpoint(&path))
+            .query(&[("hydrate", PERSON_HYDRATE)]);
+        let people: People = Self::get_json(request).await?;
+        people
+            .people
+            .into_iter()
+            .next()
+            .ok_or(MlbError::NoPerson { person_id })
+    }
+
+    /// Fetch statistics of a player.
+    ///
+    /// `season` selects the season for [`StatType::Season`] and [`StatType::GameLog`], defaulting to
+    /// the current season, and is ignored otherwise.
+    pub async fn get_player_stats(
+        &self,
+        person_id: u32,
+        group: StatGroup,
+        stat_type: StatType,
+        season: Option<i32>,
+    ) -> Result<Stats, MlbError> {
+        let path = format!("{}/{}/stats", PEOPLE_PATH, person_id);
+        let mut request = self
+            .client
+            .get(self.endpoint(&path))
+            .query(&[("stats", stat_type.as_str()), ("group", group.as_str())]);
+        if let Some(season) = season {
+            request = request.query(&[("season", season)]);
+        }
+        let response: StatsResponse = Self::get_json(request).await?;
+        Ok(response.stats.into_iter().next().unwrap_or_else(|| Stats {
+            stat_type: stat_type.as_str().to_string(),
+            group: group.as_str().to_string(),
+            splits: Vec::new(),
+        }))
+    }
+
+    /// Fetch the schedule of the current baseball day, see [`MlbClientBuilder::baseball_day`].
+    pub async fn get_schedule_today(&self, query: &ScheduleQuery) -> Result<Schedule, MlbError> {
+        self.get_schedule_via_date(&self.baseball_day.today(), query)
             .await
     }
 
-    pub async fn get_schedule_today(&self) -> Result<Schedule, Error> {
-        // TODO: Double check timezones
-        self.get_schedule_via_date(&Date::today()).await
+    pub async fn get_image<T: IntoUrl>(&self, url: T) -> Result<Vec<u8>, MlbError> {
+        Ok(Self::send(self.client.get(url))
+            .await?
+            .bytes()
+            .await?
+            .to_vec())
+    }
+}
+
+/// Builder for a configured [`MlbClient`].
+pub struct MlbClientBuilder {
+    base_url: Url,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    headers: HeaderMap,
+    proxies: Vec<Proxy>,
+    no_proxy: bool,
+    baseball_day: BaseballDay,
+    language: Language,
+}
+
+impl Default for MlbClientBuilder {
+    fn default() -> Self {
+        MlbClientBuilder {
+            base_url: Url::parse(DEFAULT_BASE_URL).unwrap(),
+            connect_timeout: None,
+            timeout: None,
+            headers: HeaderMap::new(),
+            proxies: Vec::new(),
+            no_proxy: false,
+            baseball_day: BaseballDay::default(),
+            language: Language::default(),
+        }
+    }
+}
+
+impl MlbClientBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the base URL endpoints are resolved against, e.g. `http://localhost:8080/api/`.
+    ///
+    /// A trailing slash is appended if missing so that the final path segment is kept. URLs which
+    /// cannot be a base, such as `mailto:` or `data:` URLs, are rejected by [`build`](Self::build).
+    pub fn base_url(mut self, mut base_url: Url) -> Self {
+        if !base_url.cannot_be_a_base() && !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+        self.base_url = base_url;
+        self
+    }
+
+    /// Set a timeout for establishing the connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set a timeout for the whole request, from connecting until the body has been read.
+    ///
+    /// reqwest 0.10 has no separate read timeout, so this also bounds reading the response. Use
+    /// it together with [`connect_timeout`](Self::connect_timeout) to limit connecting alone.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header sent with every request.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Add headers sent with every request.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with every request.
+    pub fn user_agent(self, value: HeaderValue) -> Self {
+        self.default_header(header::USER_AGENT, value)
     }
 
-    pub async fn get_image<T: IntoUrl>(&self, url: T) -> Result<Vec<u8>, Error> {
-        Ok(self.client.get(url).send().await?.bytes().await?.to_vec())
+    /// Route requests through a proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Disable all proxies, including system proxies picked up from the environment.
+    pub fn no_proxy(mut self) -> Self {
+        self.no_proxy = true;
+        self
+    }
+
+    /// Set the rule deciding which date is today, rolling over at 6am US/Eastern by default.
+    pub fn baseball_day(mut self, baseball_day: BaseballDay) -> Self {
+        self.baseball_day = baseball_day;
+        self
+    }
+
+    /// Set the preferred language of editorial content, English by default.
+    ///
+    /// Content missing in this language falls back to the other, see
+    /// [`Schedule::into_item_metadata_data`](types::Schedule::into_item_metadata_data).
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    pub fn build(self) -> Result<MlbClient, MlbError> {
+        if self.base_url.cannot_be_a_base() {
+            return Err(MlbError::InvalidBaseUrl { url: self.base_url });
+        }
+        let mut builder = Client::builder().default_headers(self.headers);
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if self.no_proxy {
+            builder = builder.no_proxy();
+        } else {
+            for proxy in self.proxies {
+                builder = builder.proxy(proxy);
+            }
+        }
+
+        Ok(MlbClient {
+            client: builder.build()?,
+            base_url: self.base_url,
+            baseball_day: self.baseball_day,
+            language: self.language,
+        })
     }
 }
 
@@ -50,11 +504,15 @@ impl MlbClient {
 mod tests {
     use super::*;
 
+    use mock::MockServer;
+
     #[tokio::test]
     async fn santity_fetch_schedule() {
         let client = MlbClient::new();
         let example_date = time::date!(2018 - 06 - 10);
-        let schedule = client.get_schedule_via_date(&example_date).await;
+        let schedule = client
+            .get_schedule_via_date(&example_date, &ScheduleQuery::default())
+            .await;
 
         assert!(schedule.is_ok())
     }
@@ -62,11 +520,536 @@ mod tests {
     #[tokio::test]
     async fn santity_fetch_schedule_today() {
         let client = MlbClient::new();
-        let schedule_today = client.get_schedule_today().await.unwrap();
+        let schedule_today = client
+            .get_schedule_today(&ScheduleQuery::default())
+            .await
+            .unwrap();
 
-        let today = Date::today();
-        let schedule = client.get_schedule_via_date(&today).await.unwrap();
+        let today = client.baseball_day().today();
+        let schedule = client
+            .get_schedule_via_date(&today, &ScheduleQuery::default())
+            .await
+            .unwrap();
 
         assert_eq!(schedule, schedule_today)
     }
+
+    #[test]
+    fn builder_appends_trailing_slash() {
+        let client = MlbClient::builder()
+            .base_url(Url::parse("http://localhost:8080/api").unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            client.endpoint(SCHEDULE_PATH).as_str(),
+            "http://localhost:8080/api/v1/schedule"
+        )
+    }
+
+    #[test]
+    fn builder_rejects_invalid_base_url() {
+        let result = MlbClient::builder()
+            .base_url(Url::parse("mailto:stats@example.com").unwrap())
+            .build();
+
+        match result {
+            Err(MlbError::InvalidBaseUrl { url }) => assert_eq!(url.scheme(), "mailto"),
+            Err(other) => panic!("expected invalid base URL, got {:?}", other),
+            Ok(_) => panic!("expected invalid base URL"),
+        }
+    }
+
+    #[tokio::test]
+    async fn builder_base_url_and_headers() {
+        let server = MockServer::start(vec![(
+            SCHEDULE_PATH,
+            200,
+            r#"{"dates":[{"date":"2018-06-10","games":[]}]}"#.to_string(),
+        )]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .user_agent(HeaderValue::from_static("take-home-test"))
+            .timeout(Duration::from_secs(5))
+            .no_proxy()
+            .build()
+            .unwrap();
+
+        let schedule = client
+            .get_schedule_via_date(&time::date!(2018 - 06 - 10), &ScheduleQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(schedule.dates.len(), 1);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].target.starts_with("/api/v1/schedule?"));
+        assert!(requests[0].target.contains("date=2018-06-10"));
+        assert_eq!(requests[0].header("user-agent"), Some("take-home-test"));
+    }
+
+    #[tokio::test]
+    async fn error_variants() {
+        let server = MockServer::start(vec![
+            (SCHEDULE_PATH, 200, r#"{"dates":[]}"#.to_string()),
+            (SCHEDULE_PATH, 503, "maintenance".to_string()),
+            (
+                SCHEDULE_PATH,
+                200,
+                r#"{"dates":[{"date":"2018-06-10","games":[{"gamePk":"x"}]}]}"#.to_string(),
+            ),
+        ]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+        let date = time::date!(2018 - 06 - 10);
+        let query = ScheduleQuery::default();
+
+        match client.get_schedule_via_date(&date, &query).await {
+            Err(MlbError::NoData { date: no_data }) => assert_eq!(no_data, date),
+            other => panic!("expected no data, got {:?}", other),
+        }
+        match client.get_schedule_via_date(&date, &query).await {
+            Err(MlbError::Status { code, body }) => {
+                assert_eq!(code.as_u16(), 503);
+                assert_eq!(body, "maintenance");
+            }
+            other => panic!("expected status error, got {:?}", other),
+        }
+        match client.get_schedule_via_date(&date, &query).await {
+            Err(MlbError::Decode { path, .. }) => assert_eq!(path, "dates[0].games[0].gamePk"),
+            other => panic!("expected decode error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn schedule_range_chunks() {
+        let server = MockServer::start(vec![
+            (
+                SCHEDULE_PATH,
+                200,
+                r#"{"dates":[{"date":"2018-06-01","games":[]},{"date":"2018-06-30","games":[]}]}"#
+                    .to_string(),
+            ),
+            (
+                SCHEDULE_PATH,
+                200,
+                r#"{"dates":[{"date":"2018-07-02","games":[]}]}"#.to_string(),
+            ),
+        ]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let date_items = client
+            .get_schedule_range(
+                &time::date!(2018 - 06 - 01),
+                &time::date!(2018 - 07 - 15),
+                &ScheduleQuery::new().team_id(147),
+            )
+            .await
+            .unwrap();
+        let dates: Vec<Date> = date_items.iter().map(|item| item.date).collect();
+        assert_eq!(
+            dates,
+            vec![
+                time::date!(2018 - 06 - 01),
+                time::date!(2018 - 06 - 30),
+                time::date!(2018 - 07 - 02)
+            ]
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0]
+            .target
+            .contains("startDate=2018-06-01&endDate=2018-07-01"));
+        assert!(requests[1]
+            .target
+            .contains("startDate=2018-07-02&endDate=2018-07-15"));
+    }
+
+    #[tokio::test]
+    async fn fetch_linescore() {
+        let server = MockServer::start(vec![(
+            "v1/game/530769/linescore",
+            200,
+            r#"{
+                "currentInning": 9,
+                "inningState": "Bottom",
+                "scheduledInnings": 9,
+                "innings": [
+                    {"num": 1, "home": {"runs": 1, "hits": 2, "errors": 0}, "away": {"runs": 0, "hits": 0, "errors": 0}},
+                    {"num": 9, "home": {}, "away": {"runs": 2, "hits": 3, "errors": 0}}
+                ],
+                "teams": {"home": {"runs": 1, "hits": 2, "errors": 0}, "away": {"runs": 2, "hits": 3, "errors": 0}}
+            }"#
+            .to_string(),
+        )]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let linescore = client.get_linescore(530769).await.unwrap();
+        assert_eq!(linescore.innings.len(), 2);
+        assert_eq!(linescore.innings[1].home.runs, None);
+        assert_eq!(linescore.teams.away.runs, Some(2));
+        assert_eq!(server.requests()[0].target, "/api/v1/game/530769/linescore");
+    }
+
+    #[tokio::test]
+    async fn live_feed_stream_updates() {
+        use futures::StreamExt;
+
+        let live = r#"{
+            "gamePk": 530769,
+            "metaData": {"wait": 10, "timeStamp": "20180610_170500"},
+            "gameData": {
+                "status": {"abstractGameState": "Live", "detailedState": "In Progress"},
+                "teams": {
+                    "away": {"id": 147, "name": "New York Yankees"},
+                    "home": {"id": 110, "name": "Baltimore Orioles"}
+                }
+            },
+            "liveData": {
+                "plays": {"allPlays": []},
+                "linescore": {
+                    "currentInning": 1,
+                    "inningState": "Top",
+                    "teams": {"away": {"runs": 0}, "home": {"runs": 0}}
+                }
+            }
+        }"#;
+        let diff = r#"[{"diff": [
+            {
+                "op": "add",
+                "path": "/liveData/plays/allPlays/-",
+                "value": {
+                    "result": {"event": "Home Run", "description": "Aaron Judge homers.", "awayScore": 1, "homeScore": 0},
+                    "about": {"atBatIndex": 0, "halfInning": "top", "inning": 1, "isComplete": true, "isScoringPlay": true}
+                }
+            },
+            {"op": "replace", "path": "/liveData/linescore/teams/away/runs", "value": 1},
+            {"op": "replace", "path": "/metaData/timeStamp", "value": "20180610_170600"}
+        ]}]"#;
+        // Returned in place of a diff when the feed has changed too much to patch
+        let final_feed = r#"{
+            "gamePk": 530769,
+            "metaData": {"wait": 10, "timeStamp": "20180610_200000"},
+            "gameData": {
+                "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                "teams": {
+                    "away": {"id": 147, "name": "New York Yankees"},
+                    "home": {"id": 110, "name": "Baltimore Orioles"}
+                }
+            },
+            "liveData": {
+                "plays": {"allPlays": []},
+                "linescore": {
+                    "currentInning": 2,
+                    "inningState": "Top",
+                    "teams": {"away": {"runs": 1}, "home": {"runs": 0}}
+                }
+            }
+        }"#;
+        let server = MockServer::start(vec![
+            ("v1.1/game/530769/feed/live", 200, live.to_string()),
+            (
+                "v1.1/game/530769/feed/live/diffPatch",
+                200,
+                diff.to_string(),
+            ),
+            (
+                "v1.1/game/530769/feed/live/diffPatch",
+                200,
+                "[]".to_string(),
+            ),
+            (
+                "v1.1/game/530769/feed/live/diffPatch",
+                200,
+                final_feed.to_string(),
+            ),
+        ]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let updates: Vec<LiveUpdate> = client
+            .live_feed_stream(530769, Some(Duration::from_millis(1)))
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        match &updates[0] {
+            LiveUpdate::NewPlay(play) => {
+                assert_eq!(play.result.event.as_deref(), Some("Home Run"))
+            }
+            other => panic!("expected new play, got {:?}", other),
+        }
+        assert_eq!(
+            updates[1..],
+            [
+                LiveUpdate::ScoreChange { away: 1, home: 0 },
+                LiveUpdate::InningChange {
+                    inning: 2,
+                    inning_state: "Top".to_string()
+                },
+                LiveUpdate::GameEnd { away: 1, home: 0 },
+            ]
+        );
+
+        let requests = server.requests();
+        assert!(requests[1]
+            .target
+            .ends_with("startTimecode=20180610_170500"));
+        assert!(requests[2]
+            .target
+            .ends_with("startTimecode=20180610_170600"));
+    }
+
+    #[tokio::test]
+    async fn live_feed_stream_backs_off() {
+        use futures::StreamExt;
+
+        let server = MockServer::start(vec![("v1.1/game/530769/feed/live", 500, String::new())]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let start = std::time::Instant::now();
+        let results: Vec<_> = client
+            .live_feed_stream(530769, Some(Duration::from_millis(20)))
+            .take(3)
+            .collect()
+            .await;
+        assert!(results.iter().all(|result| matches!(
+            result,
+            Err(MlbError::Status { code, .. }) if code.as_u16() == 500
+        )));
+        // Retries wait 40ms and then 80ms rather than following each other immediately
+        assert!(start.elapsed() >= Duration::from_millis(120));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn live_feed_stream_ends_on_undecodable_feed() {
+        use futures::StreamExt;
+
+        let live = r#"{
+            "gamePk": 530769,
+            "metaData": {"wait": 10, "timeStamp": "20180610_170500"},
+            "gameData": {
+                "status": {"abstractGameState": "Live", "detailedState": "In Progress"},
+                "teams": {
+                    "away": {"id": 147, "name": "New York Yankees"},
+                    "home": {"id": 110, "name": "Baltimore Orioles"}
+                }
+            },
+            "liveData": {
+                "plays": {"allPlays": []},
+                "linescore": {"teams": {"away": {"runs": 0}, "home": {"runs": 0}}}
+            }
+        }"#;
+        let server = MockServer::start(vec![
+            ("v1.1/game/530769/feed/live", 200, live.to_string()),
+            (
+                "v1.1/game/530769/feed/live",
+                200,
+                r#"{"gamePk": "530769"}"#.to_string(),
+            ),
+            (
+                "v1.1/game/530769/feed/live/diffPatch",
+                200,
+                r#"[{"diff": [{"op": "rename", "path": "/gamePk"}]}]"#.to_string(),
+            ),
+        ]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let results: Vec<_> = client
+            .live_feed_stream(530769, Some(Duration::from_millis(1)))
+            .collect()
+            .await;
+        // The unparseable diff is replaced by the full feed once, which fails to decode
+        assert!(matches!(results[..], [Err(MlbError::Decode { .. })]));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[1].target.contains("diffPatch"));
+        assert!(!requests[2].target.contains("diffPatch"));
+    }
+
+    #[tokio::test]
+    async fn fetch_roster_and_person() {
+        let person = r#"{"id": 592450, "fullName": "Aaron Judge", "primaryNumber": "99"}"#;
+        let server = MockServer::start(vec![
+            (
+                "v1/teams/147/roster",
+                200,
+                format!(
+                    r#"{{"roster": [{{
+                        "person": {},
+                        "jerseyNumber": "99",
+                        "position": {{"code": "9", "name": "Outfielder", "abbreviation": "RF"}},
+                        "status": {{"code": "A", "description": "Active"}}
+                    }}]}}"#,
+                    person
+                ),
+            ),
+            (
+                "v1/people/592450",
+                200,
+                format!(r#"{{"people": [{}]}}"#, person),
+            ),
+            ("v1/people/1", 200, r#"{"people": []}"#.to_string()),
+        ]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let roster = client
+            .get_roster(147, &time::date!(2018 - 06 - 10))
+            .await
+            .unwrap();
+        assert_eq!(roster[0].position.abbreviation, "RF");
+        assert_eq!(roster[0].person.full_name, "Aaron Judge");
+        assert!(server.requests()[0].target.contains("season%3D2018"));
+
+        let person = client.get_person(592450).await.unwrap();
+        assert_eq!(person.primary_number.as_deref(), Some("99"));
+        match client.get_person(1).await {
+            Err(MlbError::NoPerson { person_id }) => assert_eq!(person_id, 1),
+            other => panic!("expected missing person, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_player_stats() {
+        let server = MockServer::start(vec![
+            (
+                "v1/people/592450/stats",
+                200,
+                r#"{"stats": [{
+                    "type": {"displayName": "yearByYear"},
+                    "group": {"displayName": "hitting"},
+                    "splits": [
+                        {"season": "2017", "stat": {"homeRuns": 52, "avg": ".284"}},
+                        {"season": "2018", "stat": {"homeRuns": 27, "avg": ".278"}}
+                    ]
+                }]}"#
+                    .to_string(),
+            ),
+            (
+                "v1/people/592450/stats",
+                200,
+                r#"{"stats": []}"#.to_string(),
+            ),
+        ]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let stats = client
+            .get_player_stats(592450, StatGroup::Hitting, StatType::YearByYear, None)
+            .await
+            .unwrap();
+        let home_runs: Vec<u32> = stats.hitting().map(|stat| stat.home_runs).collect();
+        assert_eq!(home_runs, vec![52, 27]);
+        assert!(server.requests()[0]
+            .target
+            .ends_with("stats?stats=yearByYear&group=hitting"));
+
+        let stats = client
+            .get_player_stats(592450, StatGroup::Pitching, StatType::GameLog, Some(2018))
+            .await
+            .unwrap();
+        assert_eq!(stats.group, "pitching");
+        assert!(stats.splits.is_empty());
+        assert!(server.requests()[1].target.ends_with("&season=2018"));
+    }
+
+    #[tokio::test]
+    async fn fetch_leaders_and_team_stats() {
+        let server = MockServer::start(vec![
+            (
+                "v1/stats/leaders",
+                200,
+                r#"{"leagueLeaders": [{
+                    "leaderCategory": "homeRuns",
+                    "season": "2018",
+                    "leaders": [
+                        {"rank": 1, "value": "48", "person": {"id": 547180, "fullName": "Khris Davis"},
+                         "team": {"id": 133, "name": "Oakland Athletics"}},
+                        {"rank": 2, "value": "43", "person": {"id": 545361, "fullName": "J.D. Martinez"}}
+                    ]
+                }]}"#
+                    .to_string(),
+            ),
+            (
+                "v1/teams/147/stats",
+                200,
+                r#"{"stats": [
+                    {"type": {"displayName": "season"}, "group": {"displayName": "hitting"},
+                     "splits": [{"season": "2018", "stat": {"homeRuns": 267}}]},
+                    {"type": {"displayName": "season"}, "group": {"displayName": "fielding"},
+                     "splits": [{"season": "2018", "stat": {"errors": 77}}]}
+                ]}"#
+                    .to_string(),
+            ),
+        ]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let leaders = client
+            .get_league_leaders(LeaderCategory::HomeRuns, 2018, 2)
+            .await
+            .unwrap();
+        assert_eq!(leaders.len(), 2);
+        assert_eq!(leaders[0].person.full_name, "Khris Davis");
+        assert_eq!(leaders[0].value, "48");
+        assert_eq!(leaders[1].team, None);
+        assert!(server.requests()[0]
+            .target
+            .contains("leaderCategories=homeRuns&statGroup=hitting&season=2018&limit=2"));
+
+        let stats = client.get_team_stats(147, 2018).await.unwrap();
+        assert_eq!(stats[0].hitting().next().unwrap().home_runs, 267);
+        assert_eq!(stats[1].fielding().next().unwrap().errors, 77);
+    }
+
+    #[tokio::test]
+    async fn fetch_transactions() {
+        let server = MockServer::start(vec![(
+            "v1/transactions",
+            200,
+            r#"{"transactions": [{"id": 7, "typeCode": "OPT", "description": "Optioned."}]}"#
+                .to_string(),
+        )]);
+        let client = MlbClient::builder()
+            .base_url(server.base_url())
+            .build()
+            .unwrap();
+
+        let transactions = client
+            .get_transactions(
+                &time::date!(2018 - 06 - 09),
+                &time::date!(2018 - 06 - 10),
+                Some(147),
+            )
+            .await
+            .unwrap();
+        assert_eq!(transactions[0].kind(), TransactionKind::Optioned);
+        assert!(server.requests()[0]
+            .target
+            .ends_with("transactions?startDate=2018-06-09&endDate=2018-06-10&teamId=147"));
+    }
 }
@@ -0,0 +1,410 @@
+//! Polling of the live game feed.
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    types::{AbstractGameState, LiveFeed, Play},
+    MlbClient, MlbError,
+};
+
+/// Poll interval used when the feed does not suggest one.
+const DEFAULT_WAIT: Duration = Duration::from_secs(10);
+/// Longest wait between polls while requests keep failing.
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+
+/// Change observed between two versions of the live feed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LiveUpdate {
+    /// A plate appearance was completed.
+    NewPlay(Box<Play>),
+    ScoreChange {
+        away: u32,
+        home: u32,
+    },
+    /// The inning or half inning changed, `inning_state` is e.g. "Top" or "Middle".
+    InningChange {
+        inning: u32,
+        inning_state: String,
+    },
+    /// The game reached a final state, after which the stream ends.
+    GameEnd {
+        away: u32,
+        home: u32,
+    },
+}
+
+/// Compare two versions of the feed.
+fn diff(old: &LiveFeed, new: &LiveFeed) -> Vec<LiveUpdate> {
+    let mut updates = Vec::new();
+
+    let old_complete = old
+        .live_data
+        .plays
+        .all_plays
+        .iter()
+        .filter(|play| play.about.is_complete)
+        .count();
+    updates.extend(
+        new.live_data
+            .plays
+            .all_plays
+            .iter()
+            .filter(|play| play.about.is_complete)
+            .skip(old_complete)
+            .map(|play| LiveUpdate::NewPlay(Box::new(play.clone()))),
+    );
+
+    let (old_away, old_home) = score(old);
+    let (away, home) = score(new);
+    if (old_away, old_home) != (away, home) {
+        updates.push(LiveUpdate::ScoreChange { away, home });
+    }
+
+    let old_linescore = &old.live_data.linescore;
+    let linescore = &new.live_data.linescore;
+    if (&old_linescore.current_inning, &old_linescore.inning_state)
+        != (&linescore.current_inning, &linescore.inning_state)
+    {
+        if let (Some(inning), Some(inning_state)) =
+            (linescore.current_inning, &linescore.inning_state)
+        {
+            updates.push(LiveUpdate::InningChange {
+                inning,
+                inning_state: inning_state.clone(),
+            });
+        }
+    }
+
+    if old.game_data.status.abstract_game_state != AbstractGameState::Final && is_final(new) {
+        updates.push(LiveUpdate::GameEnd { away, home });
+    }
+
+    updates
+}
+
+fn score(feed: &LiveFeed) -> (u32, u32) {
+    let teams = &feed.live_data.linescore.teams;
+    (
+        teams.away.runs.unwrap_or_default(),
+        teams.home.runs.unwrap_or_default(),
+    )
+}
+
+fn is_final(feed: &LiveFeed) -> bool {
+    feed.game_data.status.abstract_game_state == AbstractGameState::Final
+}
+
+/// Single JSON Patch (RFC 6902) operation.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum PatchOp {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+#[derive(Debug, Deserialize)]
+struct PatchSet {
+    diff: Vec<PatchOp>,
+}
+
+/// Response of the `diffPatch` endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum DiffResponse {
+    Patches(Vec<PatchSet>),
+    /// The full feed, sent when the changes are too large to patch.
+    Full(Value),
+}
+
+fn parse_pointer(pointer: &str) -> Vec<String> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+fn insert(root: &mut Value, path: &str, value: Value, replace: bool) -> Option<()> {
+    let mut tokens = parse_pointer(path);
+    let last = match tokens.pop() {
+        Some(last) => last,
+        None => {
+            *root = value;
+            return Some(());
+        }
+    };
+    let parent = tokens.iter().try_fold(root, |node, token| match node {
+        Value::Object(map) => map.get_mut(token),
+        Value::Array(array) => array.get_mut(token.parse::<usize>().ok()?),
+        _ => None,
+    })?;
+    match parent {
+        Value::Object(map) => {
+            map.insert(last, value);
+        }
+        Value::Array(array) if last == "-" => array.push(value),
+        Value::Array(array) => {
+            let index = last.parse::<usize>().ok()?;
+            if replace {
+                *array.get_mut(index)? = value;
+            } else if index <= array.len() {
+                array.insert(index, value);
+            } else {
+                return None;
+            }
+        }
+        _ => return None,
+    }
+    Some(())
+}
+
+fn remove(root: &mut Value, path: &str) -> Option<Value> {
+    let mut tokens = parse_pointer(path);
+    let last = tokens.pop()?;
+    let parent = tokens.iter().try_fold(root, |node, token| match node {
+        Value::Object(map) => map.get_mut(token),
+        Value::Array(array) => array.get_mut(token.parse::<usize>().ok()?),
+        _ => None,
+    })?;
+    match parent {
+        Value::Object(map) => map.remove(&last),
+        Value::Array(array) => {
+            let index = last.parse::<usize>().ok()?;
+            if index < array.len() {
+                Some(array.remove(index))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Apply patch operations, returning `None` if any operation does not fit the document.
+fn apply_patch(root: &mut Value, ops: Vec<PatchOp>) -> Option<()> {
+    for op in ops {
+        match op {
+            PatchOp::Add { path, value } => insert(root, &path, value, false)?,
+            PatchOp::Replace { path, value } => insert(root, &path, value, true)?,
+            PatchOp::Remove { path } => {
+                remove(root, &path)?;
+            }
+            PatchOp::Move { from, path } => {
+                let value = remove(root, &from)?;
+                insert(root, &path, value, false)?
+            }
+            PatchOp::Copy { from, path } => {
+                let value = root.pointer(&from)?.clone();
+                insert(root, &path, value, false)?
+            }
+            PatchOp::Test { path, value } => {
+                if root.pointer(&path) != Some(&value) {
+                    return None;
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+struct LiveFeedState {
+    client: MlbClient,
+    game_pk: u32,
+    poll_interval: Option<Duration>,
+    raw: Value,
+    feed: Option<LiveFeed>,
+    pending: VecDeque<LiveUpdate>,
+    finished: bool,
+    /// Polls failed in a row, doubling the wait before the next one.
+    failures: u32,
+}
+
+impl LiveFeedState {
+    fn wait(&self) -> Duration {
+        self.poll_interval.unwrap_or_else(|| {
+            self.feed
+                .as_ref()
+                .and_then(|feed| feed.meta_data.wait)
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_WAIT)
+        })
+    }
+
+    /// Wait before the next poll, doubling with each consecutive failure.
+    fn backoff(&self) -> Duration {
+        let wait = self.wait();
+        let backoff = wait
+            .checked_mul(1 << self.failures.min(16))
+            .unwrap_or(MAX_BACKOFF);
+        backoff.min(MAX_BACKOFF).max(wait)
+    }
+
+    async fn poll_feed(&mut self) -> Result<(), MlbError> {
+        let result = self.poll_feed_once().await;
+        self.failures = match result {
+            Ok(()) => 0,
+            Err(_) => self.failures.saturating_add(1),
+        };
+        // The full feed could not be decoded, polling again would fail the same way
+        if let Err(MlbError::Decode { .. }) = result {
+            self.finished = true;
+        }
+        result
+    }
+
+    async fn poll_feed_once(&mut self) -> Result<(), MlbError> {
+        let feed = match &self.feed {
+            None => {
+                // Retrying the initial fetch waits like any other poll
+                if self.failures > 0 {
+                    tokio::time::delay_for(self.backoff()).await;
+                }
+                // Initial fetch establishes the baseline which updates are relative to
+                let raw = self.client.get_live_feed_raw(self.game_pk).await?;
+                let feed: LiveFeed = crate::decode(raw.clone())?;
+                self.raw = raw;
+                if is_final(&feed) {
+                    let (away, home) = score(&feed);
+                    self.pending.push_back(LiveUpdate::GameEnd { away, home });
+                }
+                self.finished = is_final(&feed);
+                self.feed = Some(feed);
+                return Ok(());
+            }
+            Some(feed) => feed,
+        };
+
+        tokio::time::delay_for(self.backoff()).await;
+
+        let time_stamp = feed.meta_data.time_stamp.clone();
+        let response: DiffResponse = self
+            .client
+            .get_live_feed_diff(self.game_pk, &time_stamp)
+            .await?;
+        let raw = match response {
+            DiffResponse::Patches(patch_sets) => {
+                let mut raw = self.raw.clone();
+                let patched = patch_sets
+                    .into_iter()
+                    .try_for_each(|patch_set| apply_patch(&mut raw, patch_set.diff));
+                match patched {
+                    Some(()) => raw,
+                    // Patch does not apply to our version, start over from the full feed
+                    None => self.client.get_live_feed_raw(self.game_pk).await?,
+                }
+            }
+            DiffResponse::Full(raw) => raw,
+        };
+
+        // Only advance once decoded. A response that is neither patches nor a feed is replaced by
+        // the full feed, once.
+        let (raw, new_feed) = match crate::decode::<LiveFeed>(raw.clone()) {
+            Ok(new_feed) => (raw, new_feed),
+            Err(_) => {
+                let raw = self.client.get_live_feed_raw(self.game_pk).await?;
+                let new_feed = crate::decode(raw.clone())?;
+                (raw, new_feed)
+            }
+        };
+        self.raw = raw;
+        self.pending.extend(diff(feed, &new_feed));
+        self.finished = is_final(&new_feed);
+        self.feed = Some(new_feed);
+        Ok(())
+    }
+}
+
+/// Stream of [`LiveUpdate`]s produced by polling the live feed of a game.
+///
+/// Updates are relative to the feed at the time of the first poll. The stream ends after
+/// [`LiveUpdate::GameEnd`]; errors are yielded and polling continues, waiting twice as long after
+/// each consecutive failure. A full feed that cannot be decoded ends the stream after its error.
+pub struct LiveFeedStream {
+    inner: BoxStream<'static, Result<LiveUpdate, MlbError>>,
+}
+
+impl LiveFeedStream {
+    /// Poll at `poll_interval`, or at the interval suggested by the feed if `None`.
+    pub(crate) fn new(client: MlbClient, game_pk: u32, poll_interval: Option<Duration>) -> Self {
+        let state = LiveFeedState {
+            client,
+            game_pk,
+            poll_interval,
+            raw: Value::Null,
+            feed: None,
+            pending: VecDeque::new(),
+            finished: false,
+            failures: 0,
+        };
+        let inner = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(update) = state.pending.pop_front() {
+                    return Some((Ok(update), state));
+                }
+                if state.finished {
+                    return None;
+                }
+                if let Err(err) = state.poll_feed().await {
+                    return Some((Err(err), state));
+                }
+            }
+        });
+        LiveFeedStream {
+            inner: inner.boxed(),
+        }
+    }
+}
+
+impl Stream for LiveFeedStream {
+    type Item = Result<LiveUpdate, MlbError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn apply_patch_ops() {
+        let mut doc = json!({"a": {"b": [1, 2]}, "c": "x"});
+        let ops: Vec<PatchOp> = serde_json::from_value(json!([
+            {"op": "replace", "path": "/c", "value": "y"},
+            {"op": "add", "path": "/a/b/-", "value": 3},
+            {"op": "add", "path": "/a/b/0", "value": 0},
+            {"op": "remove", "path": "/a/b/1"},
+            {"op": "copy", "from": "/c", "path": "/d"},
+            {"op": "move", "from": "/d", "path": "/a~1e"}
+        ]))
+        .unwrap();
+
+        assert_eq!(apply_patch(&mut doc, ops), Some(()));
+        assert_eq!(doc, json!({"a": {"b": [0, 2, 3]}, "c": "y", "a/e": "y"}));
+    }
+
+    #[test]
+    fn apply_patch_mismatch() {
+        let mut doc = json!({"a": [1]});
+        let ops: Vec<PatchOp> =
+            serde_json::from_value(json!([{"op": "replace", "path": "/a/3", "value": 2}])).unwrap();
+
+        assert_eq!(apply_patch(&mut doc, ops), None);
+    }
+}
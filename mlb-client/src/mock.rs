@@ -0,0 +1,139 @@
+//! Minimal HTTP server standing in for the stats API in tests.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use reqwest::Url;
+
+/// A request received by the [`MockServer`].
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub target: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct Route {
+    pattern: &'static str,
+    status: u16,
+    body: String,
+    served: bool,
+}
+
+/// Serves canned responses for paths relative to `/api/`.
+///
+/// The longest pattern prefixing the request path wins. Repeated patterns are served in order,
+/// with the last one repeating once the others are used up.
+pub struct MockServer {
+    port: u16,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start(routes: Vec<(&'static str, u16, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let routes: Vec<Route> = routes
+            .into_iter()
+            .map(|(pattern, status, body)| Route {
+                pattern,
+                status,
+                body,
+                served: false,
+            })
+            .collect();
+
+        let requests_inner = requests.clone();
+        thread::spawn(move || {
+            let mut routes = routes;
+            for stream in listener.incoming().flatten() {
+                handle(stream, &mut routes, &requests_inner);
+            }
+        });
+
+        MockServer { port, requests }
+    }
+
+    pub fn base_url(&self) -> Url {
+        Url::parse(&format!("http://127.0.0.1:{}/api/", self.port)).unwrap()
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn handle(stream: TcpStream, routes: &mut [Route], requests: &Mutex<Vec<Request>>) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    let target = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or_default()
+        .to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).is_err() || line.trim().is_empty() {
+            break;
+        }
+        if let Some(index) = line.find(':') {
+            let (key, value) = line.split_at(index);
+            headers.push((key.trim().to_string(), value[1..].trim().to_string()));
+        }
+    }
+
+    let path = target
+        .trim_start_matches("/api/")
+        .split('?')
+        .next()
+        .unwrap_or_default();
+    let longest = routes
+        .iter()
+        .filter(|route| path.starts_with(route.pattern))
+        .map(|route| route.pattern.len())
+        .max();
+    let (status, body) = match longest {
+        Some(len) => {
+            let mut candidates = routes
+                .iter_mut()
+                .filter(|route| path.starts_with(route.pattern) && route.pattern.len() == len)
+                .collect::<Vec<_>>();
+            let n_candidates = candidates.len();
+            let route = match candidates.iter().position(|route| !route.served) {
+                Some(index) => &mut candidates[index],
+                None => &mut candidates[n_candidates - 1],
+            };
+            route.served = true;
+            (route.status, route.body.clone())
+        }
+        None => (404, String::new()),
+    };
+
+    requests.lock().unwrap().push(Request { target, headers });
+
+    let response = format!(
+        "HTTP/1.1 {} MOCK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let _ = (&stream).write_all(response.as_bytes());
+}
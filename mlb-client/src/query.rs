@@ -0,0 +1,318 @@
+use crate::hydrate::Hydrate;
+
+/// Sport ID of Major League Baseball.
+pub const SPORT_MLB: u32 = 1;
+/// Sport ID of Triple-A.
+pub const SPORT_TRIPLE_A: u32 = 11;
+/// Sport ID of Double-A.
+pub const SPORT_DOUBLE_A: u32 = 12;
+/// Sport ID of High-A.
+pub const SPORT_HIGH_A: u32 = 13;
+/// Sport ID of Single-A.
+pub const SPORT_SINGLE_A: u32 = 14;
+/// Sport ID of the rookie leagues.
+pub const SPORT_ROOKIE: u32 = 16;
+
+/// League ID of the American League.
+pub const LEAGUE_AMERICAN: u32 = 103;
+/// League ID of the National League.
+pub const LEAGUE_NATIONAL: u32 = 104;
+
+/// Kind of game as used by the `gameType` filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameType {
+    Exhibition,
+    Spring,
+    Regular,
+    WildCard,
+    DivisionSeries,
+    LeagueChampionship,
+    WorldSeries,
+    AllStar,
+}
+
+impl GameType {
+    /// Every postseason round.
+    pub const POSTSEASON: [GameType; 4] = [
+        GameType::WildCard,
+        GameType::DivisionSeries,
+        GameType::LeagueChampionship,
+        GameType::WorldSeries,
+    ];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            GameType::Exhibition => "E",
+            GameType::Spring => "S",
+            GameType::Regular => "R",
+            GameType::WildCard => "F",
+            GameType::DivisionSeries => "D",
+            GameType::LeagueChampionship => "L",
+            GameType::WorldSeries => "W",
+            GameType::AllStar => "A",
+        }
+    }
+}
+
+/// Group of statistics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatGroup {
+    Hitting,
+    Pitching,
+    Fielding,
+}
+
+impl StatGroup {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StatGroup::Hitting => "hitting",
+            StatGroup::Pitching => "pitching",
+            StatGroup::Fielding => "fielding",
+        }
+    }
+}
+
+/// Period statistics are aggregated over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatType {
+    Season,
+    Career,
+    /// One split per season and team.
+    YearByYear,
+    /// One split per game.
+    GameLog,
+}
+
+impl StatType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StatType::Season => "season",
+            StatType::Career => "career",
+            StatType::YearByYear => "yearByYear",
+            StatType::GameLog => "gameLog",
+        }
+    }
+}
+
+/// Statistic league leaders are ranked by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaderCategory {
+    BattingAverage,
+    HomeRuns,
+    RunsBattedIn,
+    StolenBases,
+    OnBasePlusSlugging,
+    EarnedRunAverage,
+    Strikeouts,
+    Wins,
+    Saves,
+    Whip,
+}
+
+impl LeaderCategory {
+    pub const ALL: [LeaderCategory; 10] = [
+        LeaderCategory::BattingAverage,
+        LeaderCategory::HomeRuns,
+        LeaderCategory::RunsBattedIn,
+        LeaderCategory::StolenBases,
+        LeaderCategory::OnBasePlusSlugging,
+        LeaderCategory::EarnedRunAverage,
+        LeaderCategory::Strikeouts,
+        LeaderCategory::Wins,
+        LeaderCategory::Saves,
+        LeaderCategory::Whip,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LeaderCategory::BattingAverage => "battingAverage",
+            LeaderCategory::HomeRuns => "homeRuns",
+            LeaderCategory::RunsBattedIn => "runsBattedIn",
+            LeaderCategory::StolenBases => "stolenBases",
+            LeaderCategory::OnBasePlusSlugging => "onBasePlusSlugging",
+            LeaderCategory::EarnedRunAverage => "earnedRunAverage",
+            LeaderCategory::Strikeouts => "strikeouts",
+            LeaderCategory::Wins => "wins",
+            LeaderCategory::Saves => "saves",
+            LeaderCategory::Whip => "walksAndHitsPerInningPitched",
+        }
+    }
+
+    /// Short label, e.g. "HR".
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            LeaderCategory::BattingAverage => "AVG",
+            LeaderCategory::HomeRuns => "HR",
+            LeaderCategory::RunsBattedIn => "RBI",
+            LeaderCategory::StolenBases => "SB",
+            LeaderCategory::OnBasePlusSlugging => "OPS",
+            LeaderCategory::EarnedRunAverage => "ERA",
+            LeaderCategory::Strikeouts => "SO",
+            LeaderCategory::Wins => "W",
+            LeaderCategory::Saves => "SV",
+            LeaderCategory::Whip => "WHIP",
+        }
+    }
+
+    /// Group the category is ranked within, some such as strikeouts exist in several.
+    pub fn group(self) -> StatGroup {
+        match self {
+            LeaderCategory::BattingAverage
+            | LeaderCategory::HomeRuns
+            | LeaderCategory::RunsBattedIn
+            | LeaderCategory::StolenBases
+            | LeaderCategory::OnBasePlusSlugging => StatGroup::Hitting,
+            _ => StatGroup::Pitching,
+        }
+    }
+}
+
+/// Filters and hydrations shared by every schedule request.
+///
+/// The default query selects all MLB games, hydrated with editorial recaps and decisions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduleQuery {
+    hydrate: Hydrate,
+    sport_id: u32,
+    team_ids: Vec<u32>,
+    opponent_id: Option<u32>,
+    game_types: Vec<GameType>,
+    league_ids: Vec<u32>,
+}
+
+impl Default for ScheduleQuery {
+    fn default() -> Self {
+        ScheduleQuery {
+            hydrate: Hydrate::new().editorial().decisions(),
+            sport_id: SPORT_MLB,
+            team_ids: Vec::new(),
+            opponent_id: None,
+            game_types: Vec::new(),
+            league_ids: Vec::new(),
+        }
+    }
+}
+
+impl ScheduleQuery {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Replace the additional data requested for each game.
+    pub fn hydrate(mut self, hydrate: Hydrate) -> Self {
+        self.hydrate = hydrate;
+        self
+    }
+
+    /// Select the sport, e.g. [`SPORT_TRIPLE_A`].
+    pub fn sport_id(mut self, sport_id: u32) -> Self {
+        self.sport_id = sport_id;
+        self
+    }
+
+    /// Only include games played by this team.
+    ///
+    /// May be called repeatedly to include several teams.
+    pub fn team_id(mut self, team_id: u32) -> Self {
+        self.team_ids.push(team_id);
+        self
+    }
+
+    /// Only include games against this opponent.
+    pub fn opponent_id(mut self, opponent_id: u32) -> Self {
+        self.opponent_id = Some(opponent_id);
+        self
+    }
+
+    /// Only include games of this type.
+    ///
+    /// May be called repeatedly to include several types.
+    pub fn game_type(mut self, game_type: GameType) -> Self {
+        self.game_types.push(game_type);
+        self
+    }
+
+    /// Only include games of these types, e.g. [`GameType::POSTSEASON`].
+    pub fn game_types(mut self, game_types: &[GameType]) -> Self {
+        self.game_types.extend_from_slice(game_types);
+        self
+    }
+
+    /// Only include games in this league.
+    ///
+    /// May be called repeatedly to include several leagues.
+    pub fn league_id(mut self, league_id: u32) -> Self {
+        self.league_ids.push(league_id);
+        self
+    }
+
+    /// Render as request query parameters.
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        fn join<T, F: Fn(&T) -> String>(items: &[T], f: F) -> String {
+            items.iter().map(f).collect::<Vec<_>>().join(",")
+        }
+
+        let mut params = vec![("sportId", self.sport_id.to_string())];
+        if !self.hydrate.is_empty() {
+            params.push(("hydrate", self.hydrate.to_string()));
+        }
+        if !self.team_ids.is_empty() {
+            params.push(("teamId", join(&self.team_ids, u32::to_string)));
+        }
+        if let Some(opponent_id) = self.opponent_id {
+            params.push(("opponentId", opponent_id.to_string()));
+        }
+        if !self.game_types.is_empty() {
+            params.push((
+                "gameType",
+                join(&self.game_types, |game_type| game_type.code().to_string()),
+            ));
+        }
+        if !self.league_ids.is_empty() {
+            params.push(("leagueId", join(&self.league_ids, u32::to_string)));
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_params() {
+        assert_eq!(
+            ScheduleQuery::new().to_params(),
+            vec![
+                ("sportId", "1".to_string()),
+                (
+                    "hydrate",
+                    "game(content(editorial(recap))),decisions".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_params() {
+        let query = ScheduleQuery::new()
+            .hydrate(Hydrate::new())
+            .sport_id(SPORT_TRIPLE_A)
+            .team_id(147)
+            .team_id(121)
+            .opponent_id(111)
+            .game_types(&GameType::POSTSEASON)
+            .league_id(103);
+
+        assert_eq!(
+            query.to_params(),
+            vec![
+                ("sportId", "11".to_string()),
+                ("teamId", "147,121".to_string()),
+                ("opponentId", "111".to_string()),
+                ("gameType", "F,D,L,W".to_string()),
+                ("leagueId", "103".to_string()),
+            ]
+        );
+    }
+}
@@ -1,17 +1,59 @@
-use std::collections::HashMap;
+mod boxscore;
+mod leaders;
+mod live;
+mod person;
+mod photo;
+mod play;
+mod standings;
+mod stats;
+mod transaction;
 
-use serde::Deserialize;
+pub use boxscore::*;
+pub use leaders::*;
+pub use live::*;
+pub use person::*;
+pub use photo::*;
+pub use play::*;
+pub use standings::*;
+pub use stats::*;
+pub use transaction::*;
 
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
-pub struct Cut {
-    pub src: String,
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer};
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+use crate::{
+    html::{self, RichText},
+    Language,
+};
+
+/// Deserialize `YYYY-MM-DD` strings into [`Date`].
+fn deserialize_date<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+    let raw = String::deserialize(deserializer)?;
+    Date::parse(&raw, "%Y-%m-%d").map_err(serde::de::Error::custom)
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
-pub struct Photo {
-    pub cuts: HashMap<String, Cut>,
+/// Deserialize `YYYY-MM-DDTHH:MM:SSZ` strings into UTC [`OffsetDateTime`].
+fn deserialize_datetime<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<OffsetDateTime, D::Error> {
+    let raw = String::deserialize(deserializer)?;
+    PrimitiveDateTime::parse(&raw, "%Y-%m-%dT%H:%M:%SZ")
+        .map(PrimitiveDateTime::assume_utc)
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserialize optional `YYYY-MM-DD` strings into [`Date`].
+fn deserialize_optional_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Date>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(raw) => Date::parse(&raw, "%Y-%m-%d")
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -20,38 +62,333 @@ pub struct Mlb {
     headline: String,
     subhead: String,
     blurb: String,
+    #[serde(default)]
     photo: Photo,
+    /// Full article as HTML.
+    body: Option<String>,
 }
 
+/// Editorial article, empty until it has been written.
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Recap {
-    mlb: Mlb,
+    mlb: Option<Mlb>,
+    /// Spanish variant, often written later than the English one or not at all.
+    es: Option<Mlb>,
+}
+
+impl Recap {
+    /// The variant in `language`, or in its fallback when missing.
+    fn into_article(mut self, language: Language) -> Option<Article> {
+        let mut variant = |language: Language| {
+            match language {
+                Language::English => self.mlb.take(),
+                Language::Spanish => self.es.take(),
+            }
+            .map(|mlb| Article::new(mlb, language))
+        };
+        variant(language).or_else(|| variant(language.fallback()))
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Editorial {
-    recap: Recap,
+    recap: Option<Recap>,
+    /// Provided by the `preview` hydration.
+    preview: Option<Recap>,
+    /// Provided by the `wrap` hydration.
+    wrap: Option<Recap>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Default, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Content {
     editorial: Option<Editorial>,
 }
 
+/// Runs, hits and errors of one team.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LinescoreTeam {
+    pub runs: Option<u32>,
+    pub hits: Option<u32>,
+    pub errors: Option<u32>,
+    pub left_on_base: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LinescoreTeams {
+    #[serde(default)]
+    pub home: LinescoreTeam,
+    #[serde(default)]
+    pub away: LinescoreTeam,
+}
+
+/// Runs, hits and errors of both teams in a single inning.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Inning {
+    pub num: u32,
+    /// Empty for the bottom of an inning which was not played.
+    #[serde(default)]
+    pub home: LinescoreTeam,
+    #[serde(default)]
+    pub away: LinescoreTeam,
+}
+
+/// Provided by the `linescore` hydration and [`get_linescore`](crate::MlbClient::get_linescore).
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Linescore {
+    pub current_inning: Option<u32>,
+    pub inning_state: Option<String>,
+    pub scheduled_innings: Option<u32>,
+    #[serde(default)]
+    pub innings: Vec<Inning>,
+    /// Runs, hits and errors totals.
+    #[serde(default)]
+    pub teams: LinescoreTeams,
+}
+
+/// Provided by the `venue(location)` hydration.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub city: Option<String>,
+    pub state: Option<String>,
+    /// e.g. "AZ".
+    pub state_abbrev: Option<String>,
+    pub country: Option<String>,
+}
+
+/// Provided by the `venue(fieldInfo)` hydration.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldInfo {
+    pub capacity: Option<u32>,
+    /// e.g. "Grass".
+    pub turf_type: Option<String>,
+    /// e.g. "Open", "Dome" or "Retractable".
+    pub roof_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Venue {
+    pub id: u32,
+    pub name: String,
+    pub location: Option<Location>,
+    pub field_info: Option<FieldInfo>,
+}
+
+impl Venue {
+    /// City and state, e.g. "Phoenix, AZ".
+    pub fn place(&self) -> Option<String> {
+        let location = self.location.as_ref()?;
+        let city = location.city.as_ref()?;
+        match location.state_abbrev.as_ref().or(location.state.as_ref()) {
+            Some(state) => Some(format!("{}, {}", city, state)),
+            None => Some(city.clone()),
+        }
+    }
+
+    pub fn roof_type(&self) -> Option<&str> {
+        self.field_info.as_ref()?.roof_type.as_deref()
+    }
+}
+
+/// Provided by the `weather` hydration.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Weather {
+    pub condition: Option<String>,
+    pub temp: Option<String>,
+    pub wind: Option<String>,
+}
+
+/// Coarse state of a game.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum AbstractGameState {
+    Preview,
+    Live,
+    Final,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GameStatus {
+    pub abstract_game_state: AbstractGameState,
+    /// Fine grained state, e.g. "In Progress", "Postponed" or "Final".
+    pub detailed_state: String,
+    /// Reason for a delay or postponement, e.g. "Rain".
+    pub reason: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    pub id: u32,
+    pub name: String,
+    /// Provided by the `team` hydration.
+    pub abbreviation: Option<String>,
+}
+
+impl Team {
+    /// Abbreviation such as "NYY", falling back to the full name without the `team` hydration.
+    pub fn short_name(&self) -> &str {
+        self.abbreviation.as_deref().unwrap_or(&self.name)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueRecord {
+    pub wins: u32,
+    pub losses: u32,
+    pub pct: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GameTeam {
+    pub team: Team,
+    pub league_record: Option<LeagueRecord>,
+    /// Missing until the game has started.
+    pub score: Option<u32>,
+    /// Missing until the game has finished.
+    pub is_winner: Option<bool>,
+    /// Provided by the `probablePitcher` hydration, until the game has started.
+    pub probable_pitcher: Option<PersonDetail>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GameTeams {
+    pub away: GameTeam,
+    pub home: GameTeam,
+}
+
+/// Reference to a player or staff member.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    pub id: u32,
+    pub full_name: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub code: String,
+    pub name: String,
+    /// e.g. "SS" or "P".
+    pub abbreviation: String,
+}
+
+/// Provided by the `decisions` hydration.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Decisions {
+    pub winner: Option<Person>,
+    pub loser: Option<Person>,
+    pub save: Option<Person>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+pub enum DoubleHeader {
+    #[default]
+    #[serde(rename = "N")]
+    No,
+    /// Both games on a single ticket.
+    #[serde(rename = "Y")]
+    Traditional,
+    /// Separate admission for each game.
+    #[serde(rename = "S")]
+    Split,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum BroadcastType {
+    #[serde(rename = "TV")]
+    Tv,
+    #[serde(rename = "AM")]
+    Am,
+    #[serde(rename = "FM")]
+    Fm,
+    #[serde(other)]
+    Other,
+}
+
+impl BroadcastType {
+    pub fn is_radio(self) -> bool {
+        matches!(self, BroadcastType::Am | BroadcastType::Fm)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HomeAway {
+    Home,
+    Away,
+}
+
+/// Provided by the `broadcasts` hydration.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Broadcast {
+    pub id: u32,
+    /// e.g. "ESPN" or "WFAN 660/101.9 FM".
+    pub name: String,
+    #[serde(rename = "type")]
+    pub broadcast_type: BroadcastType,
+    /// e.g. "en" or "es".
+    pub language: Option<String>,
+    #[serde(default)]
+    pub is_national: bool,
+    pub call_sign: Option<String>,
+    /// Team whose market carries the broadcast.
+    pub home_away: Option<HomeAway>,
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Game {
     pub game_pk: u32,
-    pub game_date: String,
+    /// Scheduled start time.
+    #[serde(deserialize_with = "deserialize_datetime")]
+    pub game_date: OffsetDateTime,
+    pub status: GameStatus,
+    pub teams: GameTeams,
+    /// Game number within a doubleheader, otherwise 1.
+    #[serde(default = "default_game_number")]
+    pub game_number: u32,
+    #[serde(default)]
+    pub double_header: DoubleHeader,
+    /// e.g. "Regular Season" or "World Series".
+    pub series_description: Option<String>,
+    #[serde(default)]
     pub content: Content,
+    pub decisions: Option<Decisions>,
+    pub linescore: Option<Linescore>,
+    pub venue: Option<Venue>,
+    pub weather: Option<Weather>,
+    #[serde(default)]
+    pub broadcasts: Vec<Broadcast>,
+}
+
+fn default_game_number() -> u32 {
+    1
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DateItem {
+    #[serde(deserialize_with = "deserialize_date")]
+    pub date: Date,
     pub games: Vec<Game>,
 }
 
@@ -61,50 +398,405 @@ pub struct Schedule {
     pub dates: Vec<DateItem>,
 }
 
+/// Editorial article summarised for display.
 #[derive(Debug, PartialEq)]
-pub struct ItemMetadata {
-    pub date: String,
-    pub id: u32,
+pub struct Article {
     pub headline: String,
     pub subhead: String,
     pub blurb: String,
-    pub photos: HashMap<String, String>,
+    pub photo: Photo,
+    /// Full article, empty when the body was not provided.
+    pub body: RichText,
+    /// Language the article is written in, which differs from the one requested on fallback.
+    pub language: Language,
+}
+
+impl Article {
+    fn new(mlb: Mlb, language: Language) -> Self {
+        Article {
+            headline: mlb.headline,
+            subhead: mlb.subhead,
+            blurb: mlb.blurb,
+            photo: mlb.photo,
+            body: mlb
+                .body
+                .as_deref()
+                .map(html::to_rich_text)
+                .unwrap_or_default(),
+            language,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ItemMetadata {
+    /// Scheduled start time.
+    pub game_date: OffsetDateTime,
+    pub id: u32,
+    /// Missing until the recap is written, or when the `editorial` hydration was not requested.
+    pub recap: Option<Article>,
+    /// Provided by the `preview` hydration.
+    pub preview: Option<Article>,
+    /// Provided by the `wrap` hydration.
+    pub wrap: Option<Article>,
+    pub status: GameStatus,
+    pub teams: GameTeams,
+    pub game_number: u32,
+    pub double_header: DoubleHeader,
+    pub series_description: Option<String>,
+    pub decisions: Option<Decisions>,
+    pub venue: Option<Venue>,
+    pub weather: Option<Weather>,
+    pub broadcasts: Vec<Broadcast>,
+}
+
+impl ItemMetadata {
+    /// Summarise a game, reading its articles in `language`.
+    pub fn new(game: Game, language: Language) -> Self {
+        let article = |recap: Option<Recap>| recap.and_then(|recap| recap.into_article(language));
+        let (recap, preview, wrap) = match game.content.editorial {
+            Some(Editorial {
+                recap,
+                preview,
+                wrap,
+            }) => (article(recap), article(preview), article(wrap)),
+            None => (None, None, None),
+        };
+        ItemMetadata {
+            id: game.game_pk,
+            game_date: game.game_date,
+            recap,
+            preview,
+            wrap,
+            status: game.status,
+            teams: game.teams,
+            game_number: game.game_number,
+            double_header: game.double_header,
+            series_description: game.series_description,
+            decisions: game.decisions,
+            venue: game.venue,
+            weather: game.weather,
+            broadcasts: game.broadcasts,
+        }
+    }
+
+    /// The recap, or the preview before a recap is written.
+    pub fn article(&self) -> Option<&Article> {
+        self.recap.as_ref().or(self.preview.as_ref())
+    }
+
+    /// Every available article, in the order recap, wrap and preview.
+    pub fn articles(&self) -> impl Iterator<Item = &Article> {
+        self.recap
+            .iter()
+            .chain(self.wrap.iter())
+            .chain(self.preview.iter())
+    }
 }
 
 impl Schedule {
-    /// Compactify the JSON into the relevant item_metadata data.
+    /// Compactify the JSON into the relevant item_metadata data, keyed by the date each game
+    /// belongs to.
     ///
-    /// This will filter out any games which have missing editorials.
-    pub fn into_item_metadata_data(self) -> Vec<Vec<ItemMetadata>> {
-        self.dates
-            .into_iter()
-            .map(move |item| {
-                item.games
-                    .into_iter()
-                    .filter_map(move |game| {
-                        let id = game.game_pk;
-                        if let Some(editorial) = game.content.editorial {
-                            let mlb = editorial.recap.mlb;
-                            let photos = mlb
-                                .photo
-                                .cuts
-                                .into_iter()
-                                .map(move |(res, cut)| (res, cut.src))
-                                .collect();
-                            Some(ItemMetadata {
-                                id,
-                                date: game.game_date,
-                                headline: mlb.headline,
-                                subhead: mlb.subhead,
-                                blurb: mlb.blurb,
-                                photos,
-                            })
-                        } else {
-                            None
-                        }
-                    })
-                    .collect()
+    /// Games are keyed by the date listing them rather than by their UTC start time, so a night
+    /// game starting after midnight UTC stays on its local date. Articles are read in `language`,
+    /// falling back to the other language when they have not been written in it.
+    pub fn into_item_metadata_data(self, language: Language) -> BTreeMap<Date, Vec<ItemMetadata>> {
+        let mut item_metadata_data: BTreeMap<Date, Vec<ItemMetadata>> = BTreeMap::new();
+        for item in self.dates {
+            let item_metadatas = item
+                .games
+                .into_iter()
+                .map(|game| ItemMetadata::new(game, language));
+            item_metadata_data
+                .entry(item.date)
+                .or_default()
+                .extend(item_metadatas);
+        }
+        item_metadata_data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_hydrated_game() {
+        let raw = r#"{
+            "gamePk": 530769,
+            "gameDate": "2018-06-10T17:05:00Z",
+            "status": {"abstractGameState": "Final", "detailedState": "Final"},
+            "teams": {
+                "away": {"team": {"id": 109, "name": "Arizona Diamondbacks"}, "score": 2},
+                "home": {"team": {"id": 115, "name": "Colorado Rockies"}, "score": 3}
+            },
+            "linescore": {
+                "currentInning": 9,
+                "inningState": "Bottom",
+                "teams": {
+                    "home": {"runs": 3, "hits": 8, "errors": 0},
+                    "away": {"runs": 2, "hits": 6, "errors": 1}
+                }
+            },
+            "venue": {
+                "id": 15,
+                "name": "Chase Field",
+                "location": {"city": "Phoenix", "state": "Arizona", "stateAbbrev": "AZ"},
+                "fieldInfo": {"capacity": 48519, "turfType": "Grass", "roofType": "Retractable"}
+            },
+            "weather": {"condition": "Roof Closed", "temp": "78", "wind": "0 mph, None"},
+            "broadcasts": [
+                {"id": 4, "name": "FS Arizona", "type": "TV", "language": "en",
+                 "isNational": false, "callSign": "FSAZ", "homeAway": "away"},
+                {"id": 5, "name": "KSWV", "type": "AM", "language": "es", "homeAway": "home"},
+                {"id": 6, "name": "MLB.TV", "type": "TV-STREAM"}
+            ]
+        }"#;
+        let game: Game = serde_json::from_str(raw).unwrap();
+
+        assert_eq!(
+            game.game_date,
+            time::date!(2018 - 06 - 10)
+                .with_time(time::time!(17:05))
+                .assume_utc()
+        );
+        assert_eq!(game.content, Content::default());
+        let linescore = game.linescore.unwrap();
+        assert_eq!(linescore.current_inning, Some(9));
+        assert_eq!(linescore.teams.home.runs, Some(3));
+        assert_eq!(linescore.teams.away.errors, Some(1));
+        let venue = game.venue.unwrap();
+        assert_eq!(venue.name, "Chase Field");
+        assert_eq!(venue.place().as_deref(), Some("Phoenix, AZ"));
+        assert_eq!(venue.roof_type(), Some("Retractable"));
+        assert_eq!(game.weather.unwrap().temp.as_deref(), Some("78"));
+        let types: Vec<_> = game
+            .broadcasts
+            .iter()
+            .map(|broadcast| broadcast.broadcast_type)
+            .collect();
+        assert_eq!(
+            types,
+            vec![BroadcastType::Tv, BroadcastType::Am, BroadcastType::Other]
+        );
+        assert!(game.broadcasts[1].broadcast_type.is_radio());
+        assert_eq!(game.broadcasts[1].home_away, Some(HomeAway::Home));
+        assert_eq!(game.broadcasts[0].call_sign.as_deref(), Some("FSAZ"));
+    }
+
+    #[test]
+    fn deserialize_teams_and_status() {
+        let raw = r#"{
+            "gamePk": 530770,
+            "gameDate": "2018-06-10T23:05:00Z",
+            "status": {
+                "abstractGameState": "Final",
+                "detailedState": "Postponed",
+                "reason": "Rain"
+            },
+            "teams": {
+                "away": {
+                    "team": {"id": 147, "name": "New York Yankees", "abbreviation": "NYY"},
+                    "leagueRecord": {"wins": 42, "losses": 19, "pct": ".689"}
+                },
+                "home": {
+                    "team": {"id": 110, "name": "Baltimore Orioles", "abbreviation": "BAL"},
+                    "leagueRecord": {"wins": 19, "losses": 43, "pct": ".306"}
+                }
+            },
+            "gameNumber": 2,
+            "doubleHeader": "S",
+            "seriesDescription": "Regular Season",
+            "decisions": {
+                "winner": {"id": 592450, "fullName": "Aaron Judge"},
+                "loser": {"id": 605164, "fullName": "Dylan Bundy"}
+            }
+        }"#;
+        let game: Game = serde_json::from_str(raw).unwrap();
+
+        assert_eq!(game.status.abstract_game_state, AbstractGameState::Final);
+        assert_eq!(game.status.reason.as_deref(), Some("Rain"));
+        assert_eq!(game.teams.away.team.abbreviation.as_deref(), Some("NYY"));
+        assert_eq!(game.teams.away.team.short_name(), "NYY");
+        let team: Team =
+            serde_json::from_str(r#"{"id": 110, "name": "Baltimore Orioles"}"#).unwrap();
+        assert_eq!(team.short_name(), "Baltimore Orioles");
+        assert_eq!(game.teams.home.league_record.unwrap().losses, 43);
+        assert_eq!(game.teams.home.score, None);
+        assert_eq!(game.game_number, 2);
+        assert_eq!(game.double_header, DoubleHeader::Split);
+        assert_eq!(game.series_description.as_deref(), Some("Regular Season"));
+        let decisions = game.decisions.unwrap();
+        assert_eq!(decisions.winner.unwrap().id, 592450);
+        assert_eq!(decisions.loser.unwrap().full_name, "Dylan Bundy");
+        assert_eq!(decisions.save, None);
+    }
+
+    #[test]
+    fn preview_with_probable_pitchers() {
+        let raw = r#"{"dates": [{"date": "2018-06-10", "games": [{
+            "gamePk": 530771,
+            "gameDate": "2018-06-10T23:05:00Z",
+            "status": {"abstractGameState": "Preview", "detailedState": "Scheduled"},
+            "teams": {
+                "away": {
+                    "team": {"id": 147, "name": "New York Yankees"},
+                    "probablePitcher": {
+                        "id": 547888,
+                        "fullName": "Masahiro Tanaka",
+                        "stats": [{
+                            "type": {"displayName": "season"},
+                            "group": {"displayName": "pitching"},
+                            "splits": [{"season": "2018", "stat": {"wins": 5, "losses": 2, "era": "4.79"}}]
+                        }]
+                    }
+                },
+                "home": {"team": {"id": 110, "name": "Baltimore Orioles"}}
+            },
+            "content": {"editorial": {
+                "recap": {},
+                "preview": {"mlb": {
+                    "headline": "Yankees open set in Baltimore",
+                    "subhead": "",
+                    "blurb": "Tanaka takes the mound.",
+                    "photo": {"cuts": {}},
+                    "body": "<p>Tanaka <b>starts</b>.</p><p>Bundy opposes.</p>"
+                }}
+            }}
+        }]}]}"#;
+        let schedule: Schedule = serde_json::from_str(raw).unwrap();
+        let item_metadata = schedule
+            .into_item_metadata_data(Language::English)
+            .remove(&time::date!(2018 - 06 - 10))
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        assert_eq!(item_metadata.recap, None);
+        let article = item_metadata.article().unwrap();
+        assert_eq!(article.headline, "Yankees open set in Baltimore");
+        assert_eq!(article.body.plain_text(), "Tanaka starts.\nBundy opposes.");
+        assert_eq!(item_metadata.articles().count(), 1);
+        let pitcher = item_metadata.teams.away.probable_pitcher.unwrap();
+        assert_eq!(pitcher.full_name, "Masahiro Tanaka");
+        assert_eq!(pitcher.stats[0].pitching().next().unwrap().era, "4.79");
+        assert_eq!(item_metadata.teams.home.probable_pitcher, None);
+    }
+
+    #[test]
+    fn spanish_articles_with_fallback() {
+        let raw = r#"{"dates": [{"date": "2018-06-10", "games": [{
+            "gamePk": 530769,
+            "gameDate": "2018-06-10T17:05:00Z",
+            "status": {"abstractGameState": "Final", "detailedState": "Final"},
+            "teams": {
+                "away": {"team": {"id": 109, "name": "Arizona Diamondbacks"}, "score": 2},
+                "home": {"team": {"id": 115, "name": "Colorado Rockies"}, "score": 3}
+            },
+            "content": {"editorial": {
+                "recap": {
+                    "mlb": {"headline": "Rockies walk off", "subhead": "", "blurb": ""},
+                    "es": {"headline": "Rockies dejan en el terreno", "subhead": "", "blurb": ""}
+                },
+                "wrap": {"mlb": {"headline": "Arenado delivers", "subhead": "", "blurb": ""}}
+            }}
+        }]}]}"#;
+        let item_metadata = |language: Language| {
+            let schedule: Schedule = serde_json::from_str(raw).unwrap();
+            schedule
+                .into_item_metadata_data(language)
+                .remove(&time::date!(2018 - 06 - 10))
+                .unwrap()
+                .pop()
+                .unwrap()
+        };
+
+        let english = item_metadata(Language::English);
+        assert_eq!(english.recap.unwrap().headline, "Rockies walk off");
+
+        let spanish = item_metadata(Language::Spanish);
+        let recap = spanish.recap.unwrap();
+        assert_eq!(recap.headline, "Rockies dejan en el terreno");
+        assert_eq!(recap.language, Language::Spanish);
+        // The wrap was only written in English
+        let wrap = spanish.wrap.unwrap();
+        assert_eq!(wrap.headline, "Arenado delivers");
+        assert_eq!(wrap.language, Language::English);
+    }
+
+    #[test]
+    fn keep_games_without_editorial() {
+        let raw = r#"{"dates": [{"date": "2018-06-10", "games": [{
+            "gamePk": 530772,
+            "gameDate": "2018-06-10T17:05:00Z",
+            "status": {"abstractGameState": "Final", "detailedState": "Final"},
+            "teams": {
+                "away": {"team": {"id": 109, "name": "Arizona Diamondbacks"}, "score": 2},
+                "home": {"team": {"id": 115, "name": "Colorado Rockies"}, "score": 3}
+            }
+        }]}]}"#;
+        let schedule: Schedule = serde_json::from_str(raw).unwrap();
+        let item_metadatas = schedule
+            .into_item_metadata_data(Language::English)
+            .remove(&time::date!(2018 - 06 - 10))
+            .unwrap();
+
+        assert_eq!(item_metadatas.len(), 1);
+        assert_eq!(item_metadatas[0].article(), None);
+        assert_eq!(item_metadatas[0].teams.home.score, Some(3));
+    }
+
+    #[test]
+    fn item_metadata_keyed_by_date() {
+        let raw = r#"{"dates": [
+            {"date": "2018-06-11", "games": [{
+                "gamePk": 3,
+                "gameDate": "2018-06-11T23:05:00Z",
+                "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                "teams": {
+                    "away": {"team": {"id": 109, "name": "Arizona Diamondbacks"}},
+                    "home": {"team": {"id": 115, "name": "Colorado Rockies"}}
+                }
+            }]},
+            {"date": "2018-06-10", "games": [
+                {
+                    "gamePk": 1,
+                    "gameDate": "2018-06-10T17:05:00Z",
+                    "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                    "teams": {
+                        "away": {"team": {"id": 109, "name": "Arizona Diamondbacks"}},
+                        "home": {"team": {"id": 115, "name": "Colorado Rockies"}}
+                    }
+                },
+                {
+                    "gamePk": 2,
+                    "gameDate": "2018-06-11T02:10:00Z",
+                    "status": {"abstractGameState": "Final", "detailedState": "Final"},
+                    "teams": {
+                        "away": {"team": {"id": 147, "name": "New York Yankees"}},
+                        "home": {"team": {"id": 108, "name": "Los Angeles Angels"}}
+                    }
+                }
+            ]}
+        ]}"#;
+        // Game 2 is a night game starting after midnight UTC
+        let schedule: Schedule = serde_json::from_str(raw).unwrap();
+        let item_metadata_data = schedule.into_item_metadata_data(Language::English);
+
+        let keyed: Vec<(Date, Vec<u32>)> = item_metadata_data
+            .iter()
+            .map(|(date, item_metadatas)| {
+                let ids = item_metadatas.iter().map(|item| item.id).collect();
+                (*date, ids)
             })
-            .collect()
+            .collect();
+        assert_eq!(
+            keyed,
+            vec![
+                (time::date!(2018 - 06 - 10), vec![1, 2]),
+                (time::date!(2018 - 06 - 11), vec![3]),
+            ]
+        );
     }
 }
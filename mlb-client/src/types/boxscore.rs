@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use super::{Person, Position, Team};
+
+/// Batting statistics of a player or team within a game.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BattingLine {
+    pub at_bats: u32,
+    pub runs: u32,
+    pub hits: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    pub rbi: u32,
+    pub base_on_balls: u32,
+    pub strike_outs: u32,
+    pub hit_by_pitch: u32,
+    pub stolen_bases: u32,
+    pub left_on_base: u32,
+}
+
+/// Pitching statistics of a player or team within a game.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PitchingLine {
+    /// Innings in baseball notation, e.g. "6.2" for six and two thirds.
+    pub innings_pitched: String,
+    pub hits: u32,
+    pub runs: u32,
+    pub earned_runs: u32,
+    pub base_on_balls: u32,
+    pub strike_outs: u32,
+    pub home_runs: u32,
+    pub number_of_pitches: u32,
+    pub strikes: u32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxscoreStats {
+    #[serde(default)]
+    pub batting: BattingLine,
+    #[serde(default)]
+    pub pitching: PitchingLine,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxscorePlayer {
+    pub person: Person,
+    pub jersey_number: Option<String>,
+    pub position: Option<Position>,
+    #[serde(default)]
+    pub stats: BoxscoreStats,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxscoreTeam {
+    pub team: Team,
+    /// Team totals.
+    pub team_stats: BoxscoreStats,
+    /// Players keyed by `ID` followed by their person ID, e.g. `ID592450`.
+    pub players: HashMap<String, BoxscorePlayer>,
+    /// Person IDs of players who batted, in lineup order.
+    #[serde(default)]
+    pub batters: Vec<u32>,
+    /// Person IDs of players who pitched, in order of appearance.
+    #[serde(default)]
+    pub pitchers: Vec<u32>,
+}
+
+impl BoxscoreTeam {
+    pub fn player(&self, person_id: u32) -> Option<&BoxscorePlayer> {
+        self.players.get(&format!("ID{}", person_id))
+    }
+
+    /// Batting lines in lineup order.
+    pub fn batting_lines(&self) -> impl Iterator<Item = (&Person, &BattingLine)> {
+        self.batters
+            .iter()
+            .filter_map(move |id| self.player(*id))
+            .map(|player| (&player.person, &player.stats.batting))
+    }
+
+    /// Pitching lines in order of appearance.
+    pub fn pitching_lines(&self) -> impl Iterator<Item = (&Person, &PitchingLine)> {
+        self.pitchers
+            .iter()
+            .filter_map(move |id| self.player(*id))
+            .map(|player| (&player.person, &player.stats.pitching))
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxscoreTeams {
+    pub away: BoxscoreTeam,
+    pub home: BoxscoreTeam,
+}
+
+/// Returned by [`get_boxscore`](crate::MlbClient::get_boxscore).
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Boxscore {
+    pub teams: BoxscoreTeams,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_boxscore() {
+        let raw = r#"{
+            "teams": {
+                "away": {
+                    "team": {"id": 147, "name": "New York Yankees"},
+                    "teamStats": {
+                        "batting": {"atBats": 33, "runs": 4, "hits": 9, "homeRuns": 2},
+                        "pitching": {"inningsPitched": "9.0", "strikeOuts": 11}
+                    },
+                    "players": {
+                        "ID543037": {
+                            "person": {"id": 543037, "fullName": "Gerrit Cole"},
+                            "jerseyNumber": "45",
+                            "position": {"code": "1", "name": "Pitcher", "abbreviation": "P"},
+                            "stats": {
+                                "batting": {},
+                                "pitching": {"inningsPitched": "7.0", "earnedRuns": 2, "numberOfPitches": 104}
+                            }
+                        },
+                        "ID592450": {
+                            "person": {"id": 592450, "fullName": "Aaron Judge"},
+                            "position": {"code": "9", "name": "Outfielder", "abbreviation": "RF"},
+                            "stats": {"batting": {"atBats": 4, "hits": 2, "homeRuns": 1, "rbi": 3}, "pitching": {}}
+                        }
+                    },
+                    "batters": [592450],
+                    "pitchers": [543037]
+                },
+                "home": {
+                    "team": {"id": 110, "name": "Baltimore Orioles"},
+                    "teamStats": {
+                        "batting": {"atBats": 31, "runs": 2, "hits": 6, "homeRuns": 1},
+                        "pitching": {"inningsPitched": "9.0", "strikeOuts": 8}
+                    },
+                    "players": {
+                        "ID605164": {
+                            "person": {"id": 605164, "fullName": "Dylan Bundy"},
+                            "jerseyNumber": "37",
+                            "position": {"code": "1", "name": "Pitcher", "abbreviation": "P"},
+                            "stats": {
+                                "batting": {},
+                                "pitching": {"inningsPitched": "6.2", "earnedRuns": 1, "numberOfPitches": 98}
+                            }
+                        },
+                        "ID663624": {
+                            "person": {"id": 663624, "fullName": "Ryan Mountcastle"},
+                            "position": {"code": "3", "name": "First Base", "abbreviation": "1B"},
+                            "stats": {"batting": {"atBats": 4, "hits": 1, "homeRuns": 1, "rbi": 2}, "pitching": {}}
+                        }
+                    },
+                    "batters": [663624],
+                    "pitchers": [605164]
+                }
+            }
+        }"#;
+        let boxscore: Boxscore = serde_json::from_str(raw).unwrap();
+
+        let away = &boxscore.teams.away;
+        assert_eq!(away.team_stats.batting.home_runs, 2);
+        let (person, batting) = away.batting_lines().next().unwrap();
+        assert_eq!(person.full_name, "Aaron Judge");
+        assert_eq!((batting.hits, batting.rbi), (2, 3));
+
+        let (person, pitching) = boxscore.teams.home.pitching_lines().next().unwrap();
+        assert_eq!(person.id, 605164);
+        assert_eq!(pitching.innings_pitched, "6.2");
+        assert_eq!(pitching.number_of_pitches, 98);
+    }
+}
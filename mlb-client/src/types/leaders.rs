@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+use super::{League, Person, Team};
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueLeader {
+    /// Tied players share a rank.
+    pub rank: u32,
+    /// Formatted value, e.g. "48" or ".346".
+    pub value: String,
+    pub person: Person,
+    pub team: Option<Team>,
+    pub league: Option<League>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LeaderCategoryLeaders {
+    #[serde(default)]
+    pub leaders: Vec<LeagueLeader>,
+}
+
+/// Response of the league leaders endpoint, one entry per requested category.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LeagueLeaders {
+    pub league_leaders: Vec<LeaderCategoryLeaders>,
+}
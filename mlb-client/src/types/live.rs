@@ -0,0 +1,56 @@
+use serde::Deserialize;
+
+use super::{Decisions, GameStatus, Linescore, Play, Team};
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaData {
+    /// Suggested number of seconds to wait before polling again.
+    pub wait: Option<u64>,
+    /// Timecode of this version of the feed, e.g. `20180610_200012`.
+    pub time_stamp: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveTeams {
+    pub away: Team,
+    pub home: Team,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GameData {
+    pub status: GameStatus,
+    pub teams: LiveTeams,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Plays {
+    #[serde(default)]
+    pub all_plays: Vec<Play>,
+    pub current_play: Option<Play>,
+    /// Indices into `all_plays` of plays in which runs scored.
+    #[serde(default)]
+    pub scoring_plays: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveData {
+    #[serde(default)]
+    pub plays: Plays,
+    pub linescore: Linescore,
+    pub decisions: Option<Decisions>,
+}
+
+/// Returned by [`get_live_feed`](crate::MlbClient::get_live_feed).
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveFeed {
+    pub game_pk: u32,
+    pub meta_data: MetaData,
+    pub game_data: GameData,
+    pub live_data: LiveData,
+}
@@ -0,0 +1,135 @@
+use serde::Deserialize;
+use time::Date;
+
+use super::{deserialize_optional_date, CodeDescription, Person, Position, Stats, Team};
+
+const HEADSHOT_URL: &str =
+    "https://img.mlbstatic.com/mlb-photos/image/upload/d_people:generic:headshot:67:current.png";
+
+/// URL of a player's headshot scaled to `width` pixels, to be fetched with
+/// [`get_image`](crate::MlbClient::get_image).
+pub fn headshot_url(person_id: u32, width: u32) -> String {
+    format!(
+        "{}/w_{},q_auto:best/v1/people/{}/headshot/67/current",
+        HEADSHOT_URL, width, person_id
+    )
+}
+
+impl Person {
+    pub fn headshot_url(&self, width: u32) -> String {
+        headshot_url(self.id, width)
+    }
+}
+
+/// Full profile of a player, returned by [`get_person`](crate::MlbClient::get_person).
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonDetail {
+    pub id: u32,
+    pub full_name: String,
+    pub primary_number: Option<String>,
+    pub primary_position: Option<Position>,
+    /// `L`, `R` or `S` for switch hitters.
+    pub bat_side: Option<CodeDescription>,
+    pub pitch_hand: Option<CodeDescription>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub birth_date: Option<Date>,
+    pub current_age: Option<u32>,
+    pub birth_city: Option<String>,
+    pub birth_state_province: Option<String>,
+    pub birth_country: Option<String>,
+    /// e.g. `6' 7"`.
+    pub height: Option<String>,
+    /// Pounds.
+    pub weight: Option<u32>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub mlb_debut_date: Option<Date>,
+    pub current_team: Option<Team>,
+    /// Current season statistics.
+    #[serde(default)]
+    pub stats: Vec<Stats>,
+}
+
+impl PersonDetail {
+    pub fn headshot_url(&self, width: u32) -> String {
+        headshot_url(self.id, width)
+    }
+
+    /// e.g. "Sacramento, CA, USA".
+    pub fn birth_place(&self) -> Option<String> {
+        let parts: Vec<&str> = [
+            &self.birth_city,
+            &self.birth_state_province,
+            &self.birth_country,
+        ]
+        .iter()
+        .filter_map(|part| part.as_deref())
+        .collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(", "))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct People {
+    pub people: Vec<PersonDetail>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterEntry {
+    pub person: PersonDetail,
+    pub jersey_number: Option<String>,
+    pub position: Position,
+    /// e.g. "Active" or "Injured 10-Day".
+    pub status: Option<CodeDescription>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Roster {
+    pub roster: Vec<RosterEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_person() {
+        let raw = r#"{
+            "id": 592450,
+            "fullName": "Aaron Judge",
+            "primaryNumber": "99",
+            "primaryPosition": {"code": "9", "name": "Outfielder", "abbreviation": "RF"},
+            "batSide": {"code": "R", "description": "Right"},
+            "pitchHand": {"code": "R", "description": "Right"},
+            "birthDate": "1992-04-26",
+            "currentAge": 26,
+            "birthCity": "Linden",
+            "birthStateProvince": "CA",
+            "birthCountry": "USA",
+            "height": "6' 7\"",
+            "weight": 282,
+            "mlbDebutDate": "2016-08-13",
+            "currentTeam": {"id": 147, "name": "New York Yankees"},
+            "stats": [{
+                "type": {"displayName": "season"},
+                "group": {"displayName": "hitting"},
+                "splits": [{"season": "2018", "stat": {"gamesPlayed": 61, "homeRuns": 18, "avg": ".284"}}]
+            }]
+        }"#;
+        let person: PersonDetail = serde_json::from_str(raw).unwrap();
+
+        assert_eq!(person.birth_date, Some(time::date!(1992 - 04 - 26)));
+        assert_eq!(person.birth_place().as_deref(), Some("Linden, CA, USA"));
+        assert_eq!(person.bat_side.as_ref().unwrap().code, "R");
+        let hitting = person.stats[0].hitting().next().unwrap();
+        assert_eq!((hitting.home_runs, hitting.avg.as_str()), (18, ".284"));
+        assert!(person
+            .headshot_url(213)
+            .ends_with("/w_213,q_auto:best/v1/people/592450/headshot/67/current"));
+    }
+}
@@ -0,0 +1,232 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer};
+
+/// Ratio of width to height, in lowest terms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AspectRatio {
+    pub width: u32,
+    pub height: u32,
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl AspectRatio {
+    pub const WIDESCREEN: AspectRatio = AspectRatio {
+        width: 16,
+        height: 9,
+    };
+    pub const STANDARD: AspectRatio = AspectRatio {
+        width: 4,
+        height: 3,
+    };
+
+    pub fn new(width: u32, height: u32) -> Self {
+        let divisor = gcd(width, height).max(1);
+        AspectRatio {
+            width: width / divisor,
+            height: height / divisor,
+        }
+    }
+
+    /// Whether dimensions approximately have this ratio, allowing for rounding such as 684x385.
+    pub fn matches(self, width: u32, height: u32) -> bool {
+        if height == 0 || self.height == 0 {
+            return false;
+        }
+        let expected = width as f64 * self.height as f64 / self.width as f64;
+        (expected - height as f64).abs() <= 1.0 + height as f64 * 0.01
+    }
+}
+
+impl fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.width, self.height)
+    }
+}
+
+impl FromStr for AspectRatio {
+    type Err = String;
+
+    /// Parse ratios such as "16:9".
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let mut parts = raw.splitn(2, ':').map(|part| part.trim().parse::<u32>());
+        match (parts.next(), parts.next()) {
+            (Some(Ok(width)), Some(Ok(height))) if width > 0 && height > 0 => {
+                Ok(AspectRatio::new(width, height))
+            }
+            _ => Err(format!("invalid aspect ratio {:?}", raw)),
+        }
+    }
+}
+
+fn deserialize_aspect_ratio<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<AspectRatio>, D::Error> {
+    // Unparseable ratios are recomputed from the dimensions
+    Ok(Option::<String>::deserialize(deserializer)?.and_then(|raw| raw.parse().ok()))
+}
+
+/// Image encoding, guessed from the file extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Gif,
+    Webp,
+    Other,
+}
+
+/// A single rendition of a photo.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Cut {
+    pub src: String,
+    #[serde(default)]
+    pub width: u32,
+    #[serde(default)]
+    pub height: u32,
+    #[serde(
+        default,
+        rename = "aspectRatio",
+        deserialize_with = "deserialize_aspect_ratio"
+    )]
+    raw_aspect_ratio: Option<AspectRatio>,
+    /// Double resolution rendition.
+    pub at2x: Option<String>,
+    /// Triple resolution rendition.
+    pub at3x: Option<String>,
+}
+
+impl Cut {
+    /// Aspect ratio as labelled, or computed from the dimensions.
+    pub fn aspect_ratio(&self) -> AspectRatio {
+        self.raw_aspect_ratio
+            .unwrap_or_else(|| AspectRatio::new(self.width, self.height))
+    }
+
+    pub fn format(&self) -> ImageFormat {
+        let path = self.src.split(&['?', '#'][..]).next().unwrap_or("");
+        let extension = path.rsplit('.').next().unwrap_or("").to_lowercase();
+        match extension.as_str() {
+            "jpg" | "jpeg" => ImageFormat::Jpeg,
+            "png" => ImageFormat::Png,
+            "gif" => ImageFormat::Gif,
+            "webp" => ImageFormat::Webp,
+            _ => ImageFormat::Other,
+        }
+    }
+
+    fn has_aspect_ratio(&self, aspect_ratio: AspectRatio) -> bool {
+        self.raw_aspect_ratio == Some(aspect_ratio) || aspect_ratio.matches(self.width, self.height)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Photo {
+    pub title: Option<String>,
+    pub caption: Option<String>,
+    /// Description for readers unable to see the photo.
+    pub alt_text: Option<String>,
+    /// e.g. "Rob Carr/Getty Images".
+    pub credit: Option<String>,
+    /// Renditions keyed by resolution, e.g. "684x385".
+    #[serde(default)]
+    pub cuts: HashMap<String, Cut>,
+}
+
+impl Photo {
+    /// Smallest cut at least `min_width` by `min_height`, optionally with an aspect ratio.
+    ///
+    /// Falls back to the largest cut with the aspect ratio when none are large enough.
+    pub fn best_cut(
+        &self,
+        min_width: u32,
+        min_height: u32,
+        aspect_ratio: Option<AspectRatio>,
+    ) -> Option<&Cut> {
+        let candidates = self.cuts.values().filter(|cut| match aspect_ratio {
+            Some(aspect_ratio) => cut.has_aspect_ratio(aspect_ratio),
+            None => true,
+        });
+        let area = |cut: &&Cut| (cut.width as u64 * cut.height as u64, cut.src.clone());
+
+        let (large_enough, too_small): (Vec<&Cut>, Vec<&Cut>) =
+            candidates.partition(|cut| cut.width >= min_width && cut.height >= min_height);
+        large_enough
+            .into_iter()
+            .min_by_key(area)
+            .or_else(|| too_small.into_iter().max_by_key(area))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn photo() -> Photo {
+        let raw = r#"{
+        "title": "Judge homers",
+        "caption": "Aaron Judge rounds the bases after his home run.",
+        "altText": "Aaron Judge running",
+        "credit": "Rob Carr/Getty Images",
+        "cuts": {
+            "1920x1080": {"aspectRatio": "16:9", "width": 1920, "height": 1080,
+                          "src": "https://img.mlbstatic.com/a/1920x1080.jpg"},
+            "684x385": {"aspectRatio": "16:9", "width": 684, "height": 385,
+                        "src": "https://img.mlbstatic.com/a/684x385.jpg",
+                        "at2x": "https://img.mlbstatic.com/a/1368x770.jpg"},
+            "480x270": {"width": 480, "height": 270,
+                        "src": "https://img.mlbstatic.com/a/480x270.png?v=2"},
+            "640x480": {"aspectRatio": "4:3", "width": 640, "height": 480,
+                        "src": "https://img.mlbstatic.com/a/640x480.jpg"}
+        }}"#;
+        serde_json::from_str(raw).unwrap()
+    }
+
+    #[test]
+    fn cut_properties() {
+        let photo = photo();
+        let cut = &photo.cuts["480x270"];
+
+        assert_eq!(cut.aspect_ratio(), AspectRatio::WIDESCREEN);
+        assert_eq!(cut.format(), ImageFormat::Png);
+        assert_eq!(photo.cuts["640x480"].aspect_ratio().to_string(), "4:3");
+        assert!(photo.cuts["684x385"].at2x.is_some());
+        assert_eq!(photo.credit.as_deref(), Some("Rob Carr/Getty Images"));
+        assert_eq!(photo.alt_text.as_deref(), Some("Aaron Judge running"));
+    }
+
+    #[test]
+    fn select_best_cut() {
+        let photo = photo();
+        let src =
+            |cut: Option<&Cut>| cut.map(|cut| cut.src.rsplit('/').next().unwrap().to_string());
+
+        assert_eq!(
+            src(photo.best_cut(360, 203, Some(AspectRatio::WIDESCREEN))),
+            Some("480x270.png?v=2".to_string())
+        );
+        assert_eq!(
+            src(photo.best_cut(500, 300, Some(AspectRatio::WIDESCREEN))),
+            Some("684x385.jpg".to_string())
+        );
+        assert_eq!(
+            src(photo.best_cut(500, 300, Some(AspectRatio::STANDARD))),
+            Some("640x480.jpg".to_string())
+        );
+        // Nothing is large enough, so the largest is used
+        assert_eq!(
+            src(photo.best_cut(4000, 2250, Some(AspectRatio::WIDESCREEN))),
+            Some("1920x1080.jpg".to_string())
+        );
+        assert_eq!(photo.best_cut(1, 1, Some(AspectRatio::new(1, 1))), None);
+    }
+}
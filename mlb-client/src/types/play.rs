@@ -0,0 +1,314 @@
+use serde::Deserialize;
+
+use super::Person;
+
+/// Code with a human readable description, e.g. `FF` for "Four-Seam Fastball".
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeDescription {
+    pub code: String,
+    pub description: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Count {
+    pub balls: u32,
+    pub strikes: u32,
+    pub outs: u32,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Matchup {
+    pub batter: Person,
+    /// `L` or `R`.
+    pub bat_side: CodeDescription,
+    pub pitcher: Person,
+    /// `L` or `R`.
+    pub pitch_hand: CodeDescription,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayResult {
+    /// e.g. "Strikeout" or "Home Run", missing while the plate appearance is in progress.
+    pub event: Option<String>,
+    pub description: Option<String>,
+    pub rbi: Option<u32>,
+    pub away_score: Option<u32>,
+    pub home_score: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayAbout {
+    pub at_bat_index: u32,
+    /// Either "top" or "bottom".
+    pub half_inning: String,
+    pub inning: u32,
+    #[serde(default)]
+    pub is_complete: bool,
+    #[serde(default)]
+    pub is_scoring_play: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PlayEventDetails {
+    pub description: Option<String>,
+    /// Umpire call, e.g. `B` for "Ball" or `X` for "In play, out(s)".
+    pub call: Option<CodeDescription>,
+    /// Pitch type, e.g. `SL` for "Slider".
+    #[serde(rename = "type")]
+    pub pitch_type: Option<CodeDescription>,
+    pub is_in_play: bool,
+    pub is_strike: bool,
+    pub is_ball: bool,
+}
+
+/// Location of a pitch as it crosses the plate, in feet from the catcher's perspective.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+pub struct PlateCoordinates {
+    /// Horizontal distance from the middle of the plate.
+    #[serde(rename = "pX")]
+    pub p_x: Option<f64>,
+    /// Height above the ground.
+    #[serde(rename = "pZ")]
+    pub p_z: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PitchData {
+    /// Release velocity in mph.
+    pub start_speed: Option<f64>,
+    /// Velocity at the plate in mph.
+    pub end_speed: Option<f64>,
+    pub strike_zone_top: Option<f64>,
+    pub strike_zone_bottom: Option<f64>,
+    pub coordinates: PlateCoordinates,
+    /// Gameday zone, 1 to 9 within the strike zone and 11 to 14 outside.
+    pub zone: Option<u32>,
+}
+
+/// Landing location on the Gameday field image, with home plate near (125, 200).
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HitCoordinates {
+    pub coord_x: Option<f64>,
+    pub coord_y: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HitData {
+    /// Exit velocity in mph.
+    pub launch_speed: Option<f64>,
+    /// Vertical launch angle in degrees.
+    pub launch_angle: Option<f64>,
+    /// Distance in feet.
+    pub total_distance: Option<f64>,
+    /// e.g. "line_drive" or "fly_ball".
+    pub trajectory: Option<String>,
+    pub hardness: Option<String>,
+    /// Fielder position number the ball was hit to.
+    pub location: Option<String>,
+    pub coordinates: HitCoordinates,
+}
+
+/// A pitch, pickoff or other action within a plate appearance.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayEvent {
+    pub index: u32,
+    /// e.g. "pitch", "action" or "pickoff".
+    #[serde(rename = "type")]
+    pub event_type: String,
+    #[serde(default)]
+    pub is_pitch: bool,
+    pub pitch_number: Option<u32>,
+    #[serde(default)]
+    pub details: PlayEventDetails,
+    /// Count after the event.
+    pub count: Option<Count>,
+    pub pitch_data: Option<PitchData>,
+    pub hit_data: Option<HitData>,
+}
+
+/// A pitch and its outcome, as plotted on pitch and spray charts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pitch {
+    pub number: u32,
+    pub pitch_type: Option<CodeDescription>,
+    pub call: Option<CodeDescription>,
+    /// Count after the pitch.
+    pub count: Count,
+    pub start_speed: Option<f64>,
+    pub plate: PlateCoordinates,
+    pub zone: Option<u32>,
+    /// Present when the ball was put in play.
+    pub hit_data: Option<HitData>,
+}
+
+impl PlayEvent {
+    /// View a pitch event as a pitch. `number` counts pitches of the plate appearance from 1 and
+    /// is used when the event has no pitch number of its own.
+    fn pitch(&self, number: u32) -> Pitch {
+        let pitch_data = self.pitch_data.clone().unwrap_or_default();
+        Pitch {
+            number: self.pitch_number.unwrap_or(number),
+            pitch_type: self.details.pitch_type.clone(),
+            call: self.details.call.clone(),
+            count: self.count.unwrap_or_default(),
+            start_speed: pitch_data.start_speed,
+            plate: pitch_data.coordinates,
+            zone: pitch_data.zone,
+            hit_data: self.hit_data.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RunnerMovement {
+    /// Base the runner started on, `None` for the batter.
+    pub start: Option<String>,
+    /// Base the runner reached, e.g. "2B" or "score", `None` if put out.
+    pub end: Option<String>,
+    pub out_base: Option<String>,
+    pub is_out: bool,
+    pub out_number: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunnerDetails {
+    pub event: Option<String>,
+    pub runner: Person,
+    #[serde(default)]
+    pub is_scoring_event: bool,
+    #[serde(default)]
+    pub rbi: bool,
+    #[serde(default)]
+    pub earned: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Runner {
+    #[serde(default)]
+    pub movement: RunnerMovement,
+    pub details: RunnerDetails,
+}
+
+/// A single plate appearance.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Play {
+    #[serde(default)]
+    pub result: PlayResult,
+    pub about: PlayAbout,
+    /// Count at the end of the plate appearance.
+    pub count: Option<Count>,
+    pub matchup: Option<Matchup>,
+    #[serde(default)]
+    pub play_events: Vec<PlayEvent>,
+    #[serde(default)]
+    pub runners: Vec<Runner>,
+}
+
+impl Play {
+    /// Pitches thrown during the plate appearance, in order.
+    pub fn pitches(&self) -> impl Iterator<Item = Pitch> + '_ {
+        self.play_events
+            .iter()
+            .filter(|event| event.is_pitch)
+            .zip(1..)
+            .map(|(event, number)| event.pitch(number))
+    }
+
+    /// Batted ball data of the pitch put in play, if any.
+    pub fn hit_data(&self) -> Option<&HitData> {
+        self.play_events
+            .iter()
+            .rev()
+            .find_map(|event| event.hit_data.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_play() {
+        let raw = r#"{
+            "result": {"event": "Double", "description": "Aaron Judge doubles.", "rbi": 1},
+            "about": {"atBatIndex": 4, "halfInning": "top", "inning": 1, "isComplete": true},
+            "count": {"balls": 1, "strikes": 0, "outs": 1},
+            "matchup": {
+                "batter": {"id": 592450, "fullName": "Aaron Judge"},
+                "batSide": {"code": "R", "description": "Right"},
+                "pitcher": {"id": 605164, "fullName": "Dylan Bundy"},
+                "pitchHand": {"code": "R", "description": "Right"}
+            },
+            "playEvents": [
+                {
+                    "index": 0, "type": "pitch", "isPitch": true, "pitchNumber": 1,
+                    "details": {
+                        "call": {"code": "B", "description": "Ball"},
+                        "type": {"code": "SL", "description": "Slider"},
+                        "isBall": true
+                    },
+                    "count": {"balls": 1, "strikes": 0, "outs": 0},
+                    "pitchData": {"startSpeed": 84.2, "coordinates": {"pX": 1.21, "pZ": 1.02}, "zone": 14}
+                },
+                {"index": 1, "type": "action", "details": {"description": "Mound visit."}},
+                {"index": 2, "type": "pickoff", "details": {"description": "Pickoff Attempt 1B."}},
+                {
+                    "index": 3, "type": "pitch", "isPitch": true,
+                    "details": {
+                        "call": {"code": "D", "description": "In play, no out"},
+                        "type": {"code": "FF", "description": "Four-Seam Fastball"},
+                        "isInPlay": true
+                    },
+                    "pitchData": {"startSpeed": 93.8, "coordinates": {"pX": -0.3, "pZ": 2.6}, "zone": 5},
+                    "hitData": {
+                        "launchSpeed": 108.4, "launchAngle": 14.0, "totalDistance": 305.0,
+                        "trajectory": "line_drive", "location": "8",
+                        "coordinates": {"coordX": 141.2, "coordY": 71.9}
+                    }
+                }
+            ],
+            "runners": [
+                {
+                    "movement": {"start": "1B", "end": "score", "isOut": false},
+                    "details": {"event": "Double", "runner": {"id": 519317, "fullName": "Giancarlo Stanton"}, "isScoringEvent": true, "rbi": true, "earned": true}
+                },
+                {
+                    "movement": {"start": null, "end": "2B"},
+                    "details": {"event": "Double", "runner": {"id": 592450, "fullName": "Aaron Judge"}}
+                }
+            ]
+        }"#;
+        let play: Play = serde_json::from_str(raw).unwrap();
+
+        assert_eq!(play.matchup.as_ref().unwrap().pitch_hand.code, "R");
+        let pitches: Vec<Pitch> = play.pitches().collect();
+        assert_eq!(pitches.len(), 2);
+        assert_eq!(pitches[0].pitch_type.as_ref().unwrap().code, "SL");
+        assert_eq!(pitches[0].plate.p_x, Some(1.21));
+        assert_eq!(pitches[0].count.balls, 1);
+        assert_eq!(pitches[1].start_speed, Some(93.8));
+        assert_eq!(pitches[1].number, 2);
+
+        let hit_data = play.hit_data().unwrap();
+        assert_eq!(hit_data.launch_speed, Some(108.4));
+        assert_eq!(hit_data.coordinates.coord_x, Some(141.2));
+
+        assert!(play.runners[0].details.is_scoring_event);
+        assert_eq!(play.runners[0].movement.end.as_deref(), Some("score"));
+        assert_eq!(play.runners[1].movement.start, None);
+    }
+}
@@ -0,0 +1,168 @@
+use serde::Deserialize;
+
+use super::Team;
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct League {
+    pub id: u32,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Division {
+    pub id: u32,
+    /// e.g. "American League East".
+    pub name: Option<String>,
+    /// e.g. "AL East".
+    pub name_short: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Streak {
+    /// e.g. "W3" or "L1".
+    pub streak_code: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitRecord {
+    /// e.g. "lastTen", "home" or "away".
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub pct: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitRecords {
+    #[serde(default)]
+    pub split_records: Vec<SplitRecord>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamRecord {
+    pub team: Team,
+    pub wins: u32,
+    pub losses: u32,
+    pub winning_percentage: String,
+    /// Games behind the division leader, "-" for the leader.
+    pub games_back: String,
+    pub wild_card_games_back: Option<String>,
+    pub division_rank: Option<String>,
+    pub league_rank: Option<String>,
+    pub streak: Option<Streak>,
+    pub run_differential: i32,
+    #[serde(default)]
+    pub records: SplitRecords,
+}
+
+impl TeamRecord {
+    pub fn last_ten(&self) -> Option<&SplitRecord> {
+        self.records
+            .split_records
+            .iter()
+            .find(|record| record.record_type == "lastTen")
+    }
+}
+
+/// Standings of a single division.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DivisionStandings {
+    pub league: League,
+    pub division: Option<Division>,
+    /// Teams in division rank order.
+    pub team_records: Vec<TeamRecord>,
+}
+
+/// Returned by [`get_standings`](crate::MlbClient::get_standings).
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Standings {
+    pub records: Vec<DivisionStandings>,
+}
+
+impl Standings {
+    /// Every team in a league ordered by league rank.
+    pub fn league(&self, league_id: u32) -> Vec<&TeamRecord> {
+        let mut team_records: Vec<&TeamRecord> = self
+            .records
+            .iter()
+            .filter(|division| division.league.id == league_id)
+            .flat_map(|division| division.team_records.iter())
+            .collect();
+        team_records.sort_by_key(|record| {
+            record
+                .league_rank
+                .as_ref()
+                .and_then(|rank| rank.parse::<u32>().ok())
+                .unwrap_or(u32::MAX)
+        });
+        team_records
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_standings() {
+        let raw = r#"{"records": [
+            {
+                "league": {"id": 103},
+                "division": {"id": 202, "nameShort": "AL Central"},
+                "teamRecords": [{
+                    "team": {"id": 114, "name": "Cleveland Indians"},
+                    "wins": 33, "losses": 28, "winningPercentage": ".541",
+                    "gamesBack": "-", "leagueRank": "5", "divisionRank": "1",
+                    "runDifferential": 51
+                }]
+            },
+            {
+                "league": {"id": 103},
+                "division": {"id": 201, "nameShort": "AL East"},
+                "teamRecords": [{
+                    "team": {"id": 147, "name": "New York Yankees"},
+                    "wins": 42, "losses": 20, "winningPercentage": ".677",
+                    "gamesBack": "-", "leagueRank": "2", "divisionRank": "1",
+                    "streak": {"streakCode": "W3"}, "runDifferential": 97,
+                    "records": {"splitRecords": [{"type": "lastTen", "wins": 7, "losses": 3, "pct": ".700"}]}
+                }]
+            },
+            {
+                "league": {"id": 104},
+                "division": {"id": 204, "nameShort": "NL East"},
+                "teamRecords": [{
+                    "team": {"id": 144, "name": "Atlanta Braves"},
+                    "wins": 38, "losses": 26, "winningPercentage": ".594",
+                    "gamesBack": "-", "leagueRank": "1", "divisionRank": "1",
+                    "streak": {"streakCode": "L1"}, "runDifferential": -4
+                }]
+            }
+        ]}"#;
+        let standings: Standings = serde_json::from_str(raw).unwrap();
+
+        let central = &standings.records[0].team_records[0];
+        assert_eq!(central.streak, None);
+        assert_eq!(central.last_ten(), None);
+
+        let east = &standings.records[1].team_records[0];
+        assert_eq!(east.streak.as_ref().unwrap().streak_code, "W3");
+        assert_eq!(east.last_ten().unwrap().wins, 7);
+        assert_eq!(east.run_differential, 97);
+
+        let american: Vec<u32> = standings
+            .league(103)
+            .iter()
+            .map(|record| record.team.id)
+            .collect();
+        assert_eq!(american, vec![147, 114]);
+    }
+}
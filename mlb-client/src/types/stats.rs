@@ -0,0 +1,245 @@
+use std::convert::TryFrom;
+
+use serde::Deserialize;
+use serde_json::Value;
+use time::Date;
+
+use super::{deserialize_optional_date, Position, Team};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HittingStats {
+    pub games_played: u32,
+    pub at_bats: u32,
+    pub runs: u32,
+    pub hits: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    pub rbi: u32,
+    pub base_on_balls: u32,
+    pub strike_outs: u32,
+    pub stolen_bases: u32,
+    /// Rate stats are formatted by the API, e.g. ".311".
+    pub avg: String,
+    pub obp: String,
+    pub slg: String,
+    pub ops: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PitchingStats {
+    pub games_played: u32,
+    pub games_started: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub saves: u32,
+    /// Innings in baseball notation, e.g. "123.1" for 123 and a third.
+    pub innings_pitched: String,
+    pub hits: u32,
+    pub runs: u32,
+    pub earned_runs: u32,
+    pub base_on_balls: u32,
+    pub strike_outs: u32,
+    pub home_runs: u32,
+    pub era: String,
+    pub whip: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FieldingStats {
+    pub position: Option<Position>,
+    pub games_played: u32,
+    pub games_started: u32,
+    /// Innings in baseball notation, e.g. "1234.1".
+    pub innings: String,
+    pub chances: u32,
+    pub put_outs: u32,
+    pub assists: u32,
+    pub errors: u32,
+    pub double_plays: u32,
+    /// Fielding percentage, e.g. ".987".
+    pub fielding: String,
+}
+
+/// Statistics of one group, selected by the group of the containing [`Stats`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatLine {
+    Hitting(HittingStats),
+    Pitching(PitchingStats),
+    Fielding(FieldingStats),
+    /// A group without a typed representation.
+    Other(Value),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatSplit {
+    /// e.g. "2018", missing for career totals.
+    pub season: Option<String>,
+    pub team: Option<Team>,
+    /// Date of the game, for game logs.
+    pub date: Option<Date>,
+    /// Opposing team, for game logs.
+    pub opponent: Option<Team>,
+    pub is_home: Option<bool>,
+    pub stat: StatLine,
+}
+
+/// A group of statistics of one type, e.g. season hitting.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(try_from = "RawStats")]
+pub struct Stats {
+    /// e.g. "season" or "career".
+    pub stat_type: String,
+    /// e.g. "hitting" or "pitching".
+    pub group: String,
+    pub splits: Vec<StatSplit>,
+}
+
+impl Stats {
+    /// Hitting lines of every split.
+    pub fn hitting(&self) -> impl Iterator<Item = &HittingStats> {
+        self.splits.iter().filter_map(|split| match &split.stat {
+            StatLine::Hitting(stat) => Some(stat),
+            _ => None,
+        })
+    }
+
+    /// Pitching lines of every split.
+    pub fn pitching(&self) -> impl Iterator<Item = &PitchingStats> {
+        self.splits.iter().filter_map(|split| match &split.stat {
+            StatLine::Pitching(stat) => Some(stat),
+            _ => None,
+        })
+    }
+
+    /// Fielding lines of every split.
+    pub fn fielding(&self) -> impl Iterator<Item = &FieldingStats> {
+        self.splits.iter().filter_map(|split| match &split.stat {
+            StatLine::Fielding(stat) => Some(stat),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DisplayName {
+    display_name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSplit {
+    season: Option<String>,
+    team: Option<Team>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    date: Option<Date>,
+    opponent: Option<Team>,
+    is_home: Option<bool>,
+    stat: Value,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawStats {
+    #[serde(rename = "type")]
+    stat_type: DisplayName,
+    group: DisplayName,
+    #[serde(default)]
+    splits: Vec<RawSplit>,
+}
+
+impl TryFrom<RawStats> for Stats {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawStats) -> Result<Self, Self::Error> {
+        let group = raw.group.display_name;
+        let splits = raw
+            .splits
+            .into_iter()
+            .map(|split| {
+                let stat = match group.as_str() {
+                    "hitting" => StatLine::Hitting(serde_json::from_value(split.stat)?),
+                    "pitching" => StatLine::Pitching(serde_json::from_value(split.stat)?),
+                    "fielding" => StatLine::Fielding(serde_json::from_value(split.stat)?),
+                    _ => StatLine::Other(split.stat),
+                };
+                Ok(StatSplit {
+                    season: split.season,
+                    team: split.team,
+                    date: split.date,
+                    opponent: split.opponent,
+                    is_home: split.is_home,
+                    stat,
+                })
+            })
+            .collect::<Result<_, serde_json::Error>>()?;
+        Ok(Stats {
+            stat_type: raw.stat_type.display_name,
+            group,
+            splits,
+        })
+    }
+}
+
+/// Response of the player statistics endpoint.
+#[derive(Debug, Deserialize)]
+pub(crate) struct StatsResponse {
+    pub stats: Vec<Stats>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_game_log() {
+        let raw = r#"{
+            "type": {"displayName": "gameLog"},
+            "group": {"displayName": "fielding"},
+            "splits": [{
+                "season": "2018",
+                "date": "2018-06-10",
+                "isHome": false,
+                "team": {"id": 147, "name": "New York Yankees"},
+                "opponent": {"id": 110, "name": "Baltimore Orioles"},
+                "stat": {
+                    "position": {"code": "9", "name": "Outfielder", "abbreviation": "RF"},
+                    "gamesPlayed": 1, "putOuts": 3, "assists": 1, "errors": 0, "chances": 4,
+                    "fielding": "1.000", "innings": "9.0"
+                }
+            }]
+        }"#;
+        let stats: Stats = serde_json::from_str(raw).unwrap();
+
+        assert_eq!(stats.stat_type, "gameLog");
+        let split = &stats.splits[0];
+        assert_eq!(split.date, Some(time::date!(2018 - 06 - 10)));
+        assert_eq!(split.opponent.as_ref().unwrap().id, 110);
+        assert_eq!(split.is_home, Some(false));
+        let fielding = stats.fielding().next().unwrap();
+        assert_eq!(
+            (fielding.put_outs, fielding.fielding.as_str()),
+            (3, "1.000")
+        );
+        assert_eq!(fielding.position.as_ref().unwrap().abbreviation, "RF");
+    }
+
+    #[test]
+    fn unknown_group_is_kept() {
+        let raw = r#"{
+            "type": {"displayName": "season"},
+            "group": {"displayName": "running"},
+            "splits": [{"season": "2018", "stat": {"stolenBases": 4}}]
+        }"#;
+        let stats: Stats = serde_json::from_str(raw).unwrap();
+
+        match &stats.splits[0].stat {
+            StatLine::Other(value) => assert_eq!(value["stolenBases"], 4),
+            other => panic!("expected untyped stat, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use time::Date;
+
+use super::{deserialize_optional_date, Person, Team};
+
+/// Broad category of a transaction, derived from its type code and description.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionKind {
+    Trade,
+    /// Recalled or selected to the major league roster.
+    CallUp,
+    /// Optioned to the minor leagues.
+    Optioned,
+    InjuredListPlacement,
+    InjuredListActivation,
+    Signing,
+    Release,
+    DesignatedForAssignment,
+    Other,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub id: u32,
+    pub person: Option<Person>,
+    pub from_team: Option<Team>,
+    pub to_team: Option<Team>,
+    /// Date the transaction was announced.
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub date: Option<Date>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub effective_date: Option<Date>,
+    /// e.g. "TR" or "SC".
+    pub type_code: String,
+    /// e.g. "Trade" or "Status Change".
+    pub type_desc: Option<String>,
+    /// e.g. "New York Yankees activated RHP Masahiro Tanaka from the 10-day injured list."
+    #[serde(default)]
+    pub description: String,
+}
+
+impl Transaction {
+    pub fn kind(&self) -> TransactionKind {
+        match self.type_code.as_str() {
+            "TR" => TransactionKind::Trade,
+            "CU" | "SE" => TransactionKind::CallUp,
+            "OPT" => TransactionKind::Optioned,
+            "SGN" | "SFA" => TransactionKind::Signing,
+            "REL" => TransactionKind::Release,
+            "DES" => TransactionKind::DesignatedForAssignment,
+            "SC" => {
+                // Status changes cover the injured list, formerly the disabled list
+                let description = self.description.to_lowercase();
+                if !description.contains("injured list") && !description.contains("disabled list") {
+                    TransactionKind::Other
+                } else if description.contains("activated") || description.contains("reinstated") {
+                    TransactionKind::InjuredListActivation
+                } else if description.contains("placed") || description.contains("transferred") {
+                    TransactionKind::InjuredListPlacement
+                } else {
+                    TransactionKind::Other
+                }
+            }
+            _ => TransactionKind::Other,
+        }
+    }
+}
+
+/// Response of the transactions endpoint.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Transactions {
+    #[serde(default)]
+    pub transactions: Vec<Transaction>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_kinds() {
+        let raw = r#"{"transactions": [
+            {"id": 1, "typeCode": "TR", "date": "2018-06-10", "effectiveDate": "2018-06-10",
+             "person": {"id": 1, "fullName": "A"},
+             "fromTeam": {"id": 147, "name": "New York Yankees"},
+             "toTeam": {"id": 110, "name": "Baltimore Orioles"},
+             "description": "New York Yankees traded A to Baltimore Orioles."},
+            {"id": 2, "typeCode": "SC", "description": "New York Yankees placed RHP B on the 10-day disabled list."},
+            {"id": 3, "typeCode": "SC", "description": "New York Yankees activated RHP B from the 10-day injured list."},
+            {"id": 4, "typeCode": "SC", "description": "B status changed to Reassigned."},
+            {"id": 5, "typeCode": "CU", "description": "New York Yankees recalled C from Scranton."},
+            {"id": 6, "typeCode": "XYZ"}
+        ]}"#;
+        let transactions: Transactions = serde_json::from_str(raw).unwrap();
+        let kinds: Vec<_> = transactions
+            .transactions
+            .iter()
+            .map(Transaction::kind)
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                TransactionKind::Trade,
+                TransactionKind::InjuredListPlacement,
+                TransactionKind::InjuredListActivation,
+                TransactionKind::Other,
+                TransactionKind::CallUp,
+                TransactionKind::Other,
+            ]
+        );
+        let trade = &transactions.transactions[0];
+        assert_eq!(trade.effective_date, Some(time::date!(2018 - 06 - 10)));
+        assert_eq!(trade.to_team.as_ref().unwrap().id, 110);
+    }
+}
@@ -0,0 +1,144 @@
+use client::{html::Inline, types::Article};
+
+use sdl2::{
+    render::{Canvas, TextureCreator},
+    ttf::{Font, FontStyle},
+    video::{Window, WindowContext},
+};
+
+use crate::{graphics::draw_text, strings::Strings};
+
+/// Runs of text sharing a style, making up one line.
+type Line = Vec<(String, FontStyle)>;
+
+struct LineWrapper {
+    columns: usize,
+    lines: Vec<Line>,
+    line: Line,
+    line_len: usize,
+}
+
+impl LineWrapper {
+    fn push_word(&mut self, word: &str, style: FontStyle, space_before: bool) {
+        let mut text = if space_before && self.line_len > 0 {
+            format!(" {}", word)
+        } else {
+            word.to_string()
+        };
+        if self.line_len > 0 && self.line_len + text.chars().count() > self.columns {
+            self.break_line();
+            text = word.to_string();
+        }
+        self.line_len += text.chars().count();
+        match self.line.last_mut() {
+            Some((run, run_style)) if *run_style == style => run.push_str(&text),
+            _ => self.line.push((text, style)),
+        }
+    }
+
+    fn push_text(&mut self, text: &str, style: FontStyle) {
+        for (i, word) in text.split(' ').enumerate() {
+            if !word.is_empty() {
+                self.push_word(word, style, i > 0);
+            }
+        }
+    }
+
+    fn break_line(&mut self) {
+        self.lines.push(std::mem::take(&mut self.line));
+        self.line_len = 0;
+    }
+
+    /// End a paragraph, followed by a blank line.
+    fn break_paragraph(&mut self) {
+        if self.line_len > 0 {
+            self.break_line();
+        }
+        self.lines.push(Line::new());
+    }
+}
+
+/// Wrap an article into lines of at most `columns` characters.
+fn layout(article: &Article, strings: &Strings, columns: usize) -> Vec<Line> {
+    let mut wrapper = LineWrapper {
+        columns: columns.max(1),
+        lines: Vec::new(),
+        line: Line::new(),
+        line_len: 0,
+    };
+
+    wrapper.push_text(&article.headline, FontStyle::BOLD);
+    wrapper.break_paragraph();
+    if !article.subhead.is_empty() {
+        wrapper.push_text(&article.subhead, FontStyle::ITALIC);
+        wrapper.break_paragraph();
+    }
+
+    // Fall back to the blurb for articles without a body
+    if article.body.paragraphs.is_empty() {
+        wrapper.push_text(&article.blurb, FontStyle::NORMAL);
+        wrapper.break_paragraph();
+    }
+    for paragraph in &article.body.paragraphs {
+        for inline in paragraph {
+            match inline {
+                Inline::Text(span) => {
+                    let mut style = FontStyle::NORMAL;
+                    if span.bold {
+                        style |= FontStyle::BOLD;
+                    }
+                    if span.italic {
+                        style |= FontStyle::ITALIC;
+                    }
+                    wrapper.push_text(&span.text, style);
+                }
+                Inline::Image(image) => {
+                    let label = match &image.alt {
+                        Some(alt) => format!("[{}: {}]", strings.image, alt),
+                        None => format!("[{}]", strings.image),
+                    };
+                    wrapper.push_word(&label, FontStyle::ITALIC, true);
+                }
+            }
+        }
+        wrapper.break_paragraph();
+    }
+    wrapper.lines
+}
+
+/// Draw an article starting from line `scroll`, returning the greatest useful scroll.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_article<'ttf>(
+    canvas: &mut Canvas<Window>,
+    article: &Article,
+    scroll: usize,
+    strings: &Strings,
+    font: &mut Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    window_width: u32,
+    window_height: u32,
+) -> Result<usize, String> {
+    let padding = window_width / 10;
+    let line_height = window_height / 30;
+
+    // Monospace glyphs share a width
+    let (glyph_width, glyph_height) = font.size_of("M").map_err(|e| e.to_string())?;
+    let glyph_width = (glyph_width * line_height / glyph_height.max(1)).max(1);
+    let columns = ((window_width - padding * 2) / glyph_width) as usize;
+    let rows = ((window_height - padding) / line_height) as usize;
+
+    let lines = layout(article, strings, columns);
+    let max_scroll = lines.len().saturating_sub(rows);
+
+    let mut y = (padding / 2) as i32;
+    for line in lines.iter().skip(scroll.min(max_scroll)).take(rows) {
+        let mut x = padding as i32;
+        for (text, style) in line {
+            font.set_style(*style);
+            x += draw_text(canvas, text, font, texture_creator, x, y, line_height)? as i32;
+        }
+        y += line_height as i32;
+    }
+    font.set_style(FontStyle::NORMAL);
+    Ok(max_scroll)
+}
@@ -0,0 +1,26 @@
+use client::types::PitchingStats;
+
+use crate::strings::Strings;
+
+/// A pitcher's record, e.g. "5-2 4.79 ERA 62.0 IP 59 K 1.25 WHIP".
+pub fn pitching_stats(stat: &PitchingStats, strings: &Strings) -> String {
+    let [era, innings, strike_outs, whip] = strings.pitching_columns;
+    format!(
+        "{}-{} {} {} {} {} {} {} {} {}",
+        stat.wins,
+        stat.losses,
+        stat.era,
+        era,
+        stat.innings_pitched,
+        innings,
+        stat.strike_outs,
+        strike_outs,
+        stat.whip,
+        whip
+    )
+}
+
+/// A pitcher's record preceded by a label such as the season, e.g. "2018: 5-2 4.79 ERA ...".
+pub fn pitching_line(label: &str, stat: &PitchingStats, strings: &Strings) -> String {
+    format!("{}: {}", label, pitching_stats(stat, strings))
+}
@@ -4,15 +4,22 @@ use sdl2::{
     image::LoadTexture,
     pixels::Color,
     rect::Rect,
-    render::{Texture, TextureCreator},
+    render::{Canvas, Texture, TextureCreator},
     ttf::Font,
-    video::WindowContext,
+    video::{Window, WindowContext},
 };
 
-use std::{cmp::Ordering, path::Path, time::Instant};
+use crate::{format::pitching_stats, strings::Strings};
+
+use std::{
+    cmp::Ordering,
+    path::Path,
+    time::{Duration, Instant},
+};
 
 pub const HEADER_TEXT_HEIGHT: u32 = 24;
 pub const BLURB_TEXT_HEIGHT: u32 = 20;
+const TICKER_PERIOD: Duration = Duration::from_secs(8);
 
 pub struct GfxState<'a> {
     window_width: u32,
@@ -25,6 +32,8 @@ pub struct GfxState<'a> {
     shift: i32,
     n_games: usize,
     textures: Option<Vec<Texture<'a>>>,
+    /// Whether each texture holds a loaded image.
+    loaded: Vec<bool>,
     item_metadata: Vec<ItemMetadata>,
 }
 
@@ -48,6 +57,7 @@ impl<'a> GfxState<'a> {
             selection: 0,
             shift: 0,
             textures: None,
+            loaded: Vec::new(),
             n_games: 0,
             item_metadata: Vec::with_capacity(16),
         }
@@ -55,6 +65,7 @@ impl<'a> GfxState<'a> {
 
     pub fn reset(&mut self) {
         self.textures = None;
+        self.loaded = Vec::new();
         self.n_games = 0;
         self.shift = 0;
         self.selection = 0;
@@ -63,6 +74,9 @@ impl<'a> GfxState<'a> {
 
     /// Shift selection right
     pub fn selection_right(&mut self) {
+        if self.n_games == 0 {
+            return;
+        }
         self.selection = (self.selection + 1) % self.n_games;
 
         if self.selection == 0 {
@@ -84,6 +98,9 @@ impl<'a> GfxState<'a> {
 
     /// Shift selection right
     pub fn selection_left(&mut self) {
+        if self.n_games == 0 {
+            return;
+        }
         if self.selection == 0 {
             self.selection = self.n_games - 1;
         } else {
@@ -114,6 +131,15 @@ impl<'a> GfxState<'a> {
         self.selection
     }
 
+    /// Smallest image size which is not upscaled, as drawn when selected.
+    pub fn image_size(&self) -> (u32, u32) {
+        (self.item_width * 3 / 2, self.item_height * 3 / 2)
+    }
+
+    pub fn has_image(&self, index: usize) -> bool {
+        self.loaded.get(index).copied().unwrap_or(false)
+    }
+
     pub fn get_item_texture_mut(&mut self, index: usize) -> Option<&mut Texture<'a>> {
         self.textures.as_mut().unwrap().get_mut(index)
     }
@@ -134,6 +160,7 @@ impl<'a> GfxState<'a> {
                 textures.push(texture);
             }
             self.textures = Some(textures);
+            self.loaded = vec![false; n_games];
             self.n_games = n_games;
 
             // Take item_metadatas from network state
@@ -145,6 +172,7 @@ impl<'a> GfxState<'a> {
         for (i, image_path) in image_paths.drain(..) {
             self.textures.as_mut().unwrap()[i] =
                 self.texture_creator.load_texture(Path::new(&image_path))?;
+            self.loaded[i] = true;
         }
         Ok(())
     }
@@ -208,20 +236,23 @@ impl<'a> GfxState<'a> {
     }
 }
 
+/// Loading text with animated trailing dots.
 pub fn get_loading_texture<'a, 'ttf>(
+    text: &str,
     font: &Font<'ttf, 'static>,
     start: Instant,
     texture_creator: &'a TextureCreator<WindowContext>,
 ) -> Result<Texture<'a>, String> {
     let now = Instant::now();
     let millis = now.duration_since(start).as_millis() % 1500;
-    if millis < 1500 / 3 {
-        get_text_texture("Fetching Games.  ", font, texture_creator)
+    let dots = if millis < 1500 / 3 {
+        ".  "
     } else if millis < 1500 * 2 / 3 {
-        get_text_texture("Fetching Games.. ", font, texture_creator)
+        ".. "
     } else {
-        get_text_texture("Fetching Games...", font, texture_creator)
-    }
+        "..."
+    };
+    get_text_texture(&format!("{}{}", text, dots), font, texture_creator)
 }
 
 pub fn get_text_texture<'a, 'ttf>(
@@ -237,3 +268,155 @@ pub fn get_text_texture<'a, 'ttf>(
         .create_texture_from_surface(&loading_surface)
         .map_err(|e| e.to_string())
 }
+
+/// Draw a single line of text at its natural aspect ratio, returning the width drawn.
+pub fn draw_text<'ttf>(
+    canvas: &mut Canvas<Window>,
+    text: &str,
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    x: i32,
+    y: i32,
+    height: u32,
+) -> Result<u32, String> {
+    if text.is_empty() {
+        return Ok(0);
+    }
+    let (text_width, text_height) = font.size_of(text).map_err(|e| e.to_string())?;
+    let width = text_width * height / text_height.max(1);
+    let texture = get_text_texture(text, font, texture_creator)?;
+    canvas.copy(&texture, None, Some(Rect::new(x, y, width, height)))?;
+    Ok(width)
+}
+
+/// Draw a card with the teams, their scores and the game status, for games without a photo.
+pub fn draw_scoreboard_card<'ttf>(
+    canvas: &mut Canvas<Window>,
+    item_metadata: &ItemMetadata,
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    rect: Rect,
+) -> Result<(), String> {
+    let teams = &item_metadata.teams;
+    let game_teams = [&teams.away, &teams.home];
+    // Pad both rows to the same length so names and scores line up in the monospace font
+    let name_width = game_teams
+        .iter()
+        .map(|game_team| game_team.team.short_name().chars().count())
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<String> = game_teams
+        .iter()
+        .map(|game_team| {
+            let name = game_team.team.short_name();
+            match game_team.score {
+                Some(score) => format!("{:<width$} {:>2}", name, score, width = name_width),
+                None => name.to_string(),
+            }
+        })
+        .chain(std::iter::once(item_metadata.status.detailed_state.clone()))
+        .collect();
+    draw_card(canvas, &lines, font, texture_creator, rect)
+}
+
+/// Draw a card with the matchup and both probable starters' season lines, for games yet to start.
+pub fn draw_preview_card<'ttf>(
+    canvas: &mut Canvas<Window>,
+    item_metadata: &ItemMetadata,
+    strings: &Strings,
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    rect: Rect,
+) -> Result<(), String> {
+    let teams = &item_metadata.teams;
+    let mut lines = vec![format!(
+        "{} @ {}",
+        teams.away.team.short_name(),
+        teams.home.team.short_name()
+    )];
+    for game_team in &[&teams.away, &teams.home] {
+        let name = game_team.team.short_name();
+        match &game_team.probable_pitcher {
+            Some(pitcher) => {
+                lines.push(format!("{} {}", name, pitcher.full_name));
+                if let Some(stat) = pitcher
+                    .stats
+                    .iter()
+                    .flat_map(|stats| stats.pitching())
+                    .next()
+                {
+                    lines.push(pitching_stats(stat, strings));
+                }
+            }
+            None => lines.push(format!("{} {}", name, strings.to_be_decided)),
+        }
+    }
+    draw_card(canvas, &lines, font, texture_creator, rect)
+}
+
+/// Fill a card and centre lines of text on it, scaled to share its height.
+fn draw_card<'ttf>(
+    canvas: &mut Canvas<Window>,
+    lines: &[String],
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    rect: Rect,
+) -> Result<(), String> {
+    canvas.set_draw_color(Color::RGBA(20, 30, 60, 255));
+    canvas.fill_rect(rect)?;
+    canvas.set_draw_color(Color::RGBA(0, 0, 0, 255));
+
+    let padding = rect.height() / 10;
+    let line_height = (rect.height() - padding * 2) / lines.len() as u32;
+    let mut y = rect.y() + padding as i32;
+    for line in lines {
+        let (text_width, text_height) = font.size_of(line).map_err(|e| e.to_string())?;
+        let (text_width, text_height) = (text_width.max(1), text_height.max(1));
+        // Shrink lines too wide for the card rather than squashing them
+        let height = line_height.min((rect.width() - padding * 2) * text_height / text_width);
+        let width = text_width * height / text_height;
+        let texture = get_text_texture(line, font, texture_creator)?;
+        let x = rect.x() + (rect.width() - width) as i32 / 2;
+        let line_y = y + (line_height - height) as i32 / 2;
+        canvas.copy(&texture, None, Some(Rect::new(x, line_y, width, height)))?;
+        y += line_height as i32;
+    }
+    Ok(())
+}
+
+/// Scroll texts right to left along a line, one after another, looping once all have passed.
+pub fn draw_ticker<'ttf>(
+    canvas: &mut Canvas<Window>,
+    texts: &[String],
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    elapsed: Duration,
+    y: i32,
+    height: u32,
+) -> Result<(), String> {
+    let window_width = canvas.viewport().width();
+    let widths = texts
+        .iter()
+        .map(|text| {
+            let (width, text_height) = font.size_of(text).map_err(|e| e.to_string())?;
+            Ok(width * height / text_height.max(1) + window_width)
+        })
+        .collect::<Result<Vec<u32>, String>>()?;
+    let total: u32 = widths.iter().sum();
+    if total == 0 {
+        return Ok(());
+    }
+
+    // Scroll a screen width every TICKER_PERIOD
+    let mut offset = (elapsed.as_millis() * window_width as u128 / TICKER_PERIOD.as_millis()
+        % total as u128) as u32;
+    for (text, width) in texts.iter().zip(widths) {
+        if offset < width {
+            let x = window_width as i32 - offset as i32;
+            draw_text(canvas, text, font, texture_creator, x, y, height)?;
+            return Ok(());
+        }
+        offset -= width;
+    }
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use client::{types::LeagueLeader, LeaderCategory};
+
+use sdl2::{
+    render::{Canvas, TextureCreator},
+    ttf::Font,
+    video::{Window, WindowContext},
+};
+
+use crate::{graphics::draw_text, strings::Strings};
+
+fn leader_row(leader: &LeagueLeader) -> String {
+    let team = leader
+        .team
+        .as_ref()
+        .map(|team| team.short_name())
+        .unwrap_or("-");
+    format!(
+        "{:>3}. {:<24}{:<5}{:>7}",
+        leader.rank, leader.person.full_name, team, leader.value
+    )
+}
+
+/// Draw the leaders of a category, with the other categories listed as tabs above.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_leaders<'ttf>(
+    canvas: &mut Canvas<Window>,
+    category: LeaderCategory,
+    season: i32,
+    leaders: &[LeagueLeader],
+    strings: &Strings,
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    window_width: u32,
+    window_height: u32,
+) -> Result<(), String> {
+    let padding = window_width / 20;
+    let line_height = window_height / 24;
+    let x = padding as i32;
+    let mut y = padding as i32;
+
+    draw_text(
+        canvas,
+        &strings.league_leaders.replace("{}", &season.to_string()),
+        font,
+        texture_creator,
+        x,
+        y,
+        line_height * 3 / 2,
+    )?;
+    y += (line_height * 2) as i32;
+
+    let tabs = LeaderCategory::ALL
+        .iter()
+        .map(|other| {
+            if *other == category {
+                format!("[{}]", other.abbreviation())
+            } else {
+                other.abbreviation().to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    draw_text(canvas, &tabs, font, texture_creator, x, y, line_height)?;
+    y += (line_height * 2) as i32;
+
+    for leader in leaders {
+        draw_text(
+            canvas,
+            &leader_row(leader),
+            font,
+            texture_creator,
+            x,
+            y,
+            line_height,
+        )?;
+        y += line_height as i32;
+    }
+    Ok(())
+}
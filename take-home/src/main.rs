@@ -1,9 +1,21 @@
+pub mod article;
+pub mod format;
 pub mod graphics;
+pub mod leaders;
 pub mod networking;
+pub mod roster;
+pub mod standings;
+pub mod strings;
 
-use client::MlbClient;
+use client::{
+    day::UnknownTimeZone,
+    language::UnknownLanguage,
+    types::{AbstractGameState, Decisions, DoubleHeader, GameTeam, HomeAway, ItemMetadata, Photo},
+    BaseballDay, Language, LeaderCategory, MlbClient, MlbError, TimeZone,
+};
 use graphics::*;
-use networking::NetworkState;
+use networking::{FetchState, Latest, NetworkState, PlayerCard, RosterTeam};
+use strings::Strings;
 
 use parking_lot::Mutex;
 use sdl2::{
@@ -11,13 +23,75 @@ use sdl2::{
     image::{InitFlag, LoadTexture},
     keyboard::Keycode,
     rect::Rect,
+    render::{Canvas, Texture, TextureCreator},
     ttf::Font,
+    video::{Window, WindowContext},
 };
 
-use std::{path::Path, sync::Arc, time::Instant};
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 const BACKGROUND_PATH: &str = "./assets/background.jpg";
 const FONT_PATH: &str = "./assets/RobotoMono-Regular.ttf";
+/// Lines scrolled by page up and page down in the article reader.
+const ARTICLE_PAGE_LINES: usize = 20;
+/// Wait before fetching the day's transactions again after a failure.
+const TRANSACTIONS_RETRY: Duration = Duration::from_secs(60);
+/// Environment variable selecting the zone start times are shown in, e.g. "US/Pacific".
+const TIME_ZONE_VAR: &str = "MLB_TIME_ZONE";
+/// Environment variable selecting the initial language of articles and interface text, e.g.
+/// "es". The language can be switched with E while the carousel is shown.
+const LANGUAGE_VAR: &str = "MLB_LANGUAGE";
+
+/// Screen currently displayed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Screen {
+    Carousel,
+    Standings,
+    Leaders,
+    Roster,
+    PlayerCard,
+    Article,
+}
+
+/// Message shown in place of the carousel when fetching fails.
+fn error_message(err: &MlbError, strings: &Strings) -> String {
+    match err {
+        MlbError::NoData { date } => {
+            format!("{} {}", strings.no_games_on, date.format("%Y-%m-%d"))
+        }
+        MlbError::Status { code, .. } => {
+            format!("{} ({})", strings.api_unavailable, code.as_u16())
+        }
+        MlbError::Transport(_) | MlbError::InvalidBaseUrl { .. } => {
+            strings.api_unreachable.to_string()
+        }
+        MlbError::Decode { .. } => strings.api_unexpected.to_string(),
+        MlbError::NoPerson { .. } => strings.player_not_found.to_string(),
+    }
+}
+
+/// Display loading or error text for a screen whose data is unavailable.
+fn draw_fetch_status<'ttf, T>(
+    canvas: &mut Canvas<Window>,
+    state: &FetchState<T>,
+    loading_text: &str,
+    strings: &Strings,
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    rect: Rect,
+) -> Result<(), String> {
+    let text = match state {
+        FetchState::Fetching => loading_text.to_string(),
+        FetchState::Error(err) => error_message(err, strings),
+        FetchState::Done(_) => return Ok(()),
+    };
+    let texture = get_text_texture(&text, font, texture_creator)?;
+    canvas.copy(&texture, None, Some(rect))
+}
 
 /// Split into lines so that text may fit inside rectangles.
 fn new_line_splitter<'ttf>(
@@ -32,18 +106,191 @@ fn new_line_splitter<'ttf>(
     for word in text.split_whitespace() {
         let (width, height) = font.size_of(word).map_err(|err| err.to_string())?;
         let new_len = width * target_height / height;
-        if new_len + line_len > line_width {
+        if line_len > 0 && new_len + line_len > line_width {
             lines.push(line);
-            line = format!("{} ", word);
+            line = word.to_string();
+            line_len = new_len;
+        } else if line.is_empty() {
+            line = word.to_string();
             line_len = new_len;
         } else {
             line = format!("{} {}", line, word);
             line_len += new_len;
         }
     }
+    if !line.is_empty() {
+        lines.push(line);
+    }
     Ok(lines)
 }
 
+/// Zone configured through [`TIME_ZONE_VAR`], defaulting to the zone baseball days are reckoned
+/// in.
+fn time_zone() -> Result<TimeZone, String> {
+    match std::env::var(TIME_ZONE_VAR) {
+        Ok(name) => name.parse().map_err(|err: UnknownTimeZone| err.to_string()),
+        Err(_) => Ok(BaseballDay::default().zone),
+    }
+}
+
+/// Language configured through [`LANGUAGE_VAR`], defaulting to English.
+fn language() -> Result<Language, String> {
+    match std::env::var(LANGUAGE_VAR) {
+        Ok(name) => name.parse().map_err(|err: UnknownLanguage| err.to_string()),
+        Err(_) => Ok(Language::English),
+    }
+}
+
+/// Summarise who played and the score, e.g. "NYY 4 @ BAL 2 (Final)", or the start time before
+/// the game, e.g. "NYY @ BAL (Scheduled, 7:05 pm EDT)".
+fn score_line(item_metadata: &ItemMetadata, time_zone: TimeZone, strings: &Strings) -> String {
+    let team_text = |game_team: &GameTeam| {
+        let name = game_team.team.short_name();
+        match game_team.score {
+            Some(score) => format!("{} {}", name, score),
+            None => name.to_string(),
+        }
+    };
+
+    let mut line = format!(
+        "{} @ {} ({}",
+        team_text(&item_metadata.teams.away),
+        team_text(&item_metadata.teams.home),
+        item_metadata.status.detailed_state
+    );
+    if let Some(reason) = &item_metadata.status.reason {
+        line = format!("{}: {}", line, reason);
+    }
+    if item_metadata.status.abstract_game_state == AbstractGameState::Preview {
+        let start_time = time_zone.to_local(item_metadata.game_date);
+        line = format!(
+            "{}, {} {}",
+            line,
+            start_time.format("%-I:%M %P"),
+            time_zone.abbreviation(item_metadata.game_date)
+        );
+    }
+    if item_metadata.double_header != DoubleHeader::No {
+        line = format!("{}, {} {}", line, strings.game, item_metadata.game_number);
+    }
+    format!("{})", line)
+}
+
+/// Where the game is played and in what conditions, e.g.
+/// "Chase Field, Phoenix, AZ (Retractable roof) 78°F, Roof Closed, wind 0 mph, None".
+fn venue_line(item_metadata: &ItemMetadata, strings: &Strings) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(venue) = &item_metadata.venue {
+        let mut venue_text = venue.name.clone();
+        if let Some(place) = venue.place() {
+            venue_text = format!("{}, {}", venue_text, place);
+        }
+        if let Some(roof_type) = venue.roof_type() {
+            venue_text = format!("{} ({})", venue_text, strings.roof.replace("{}", roof_type));
+        }
+        parts.push(venue_text);
+    }
+    if let Some(weather) = &item_metadata.weather {
+        let mut weather_text = Vec::new();
+        if let Some(temp) = &weather.temp {
+            weather_text.push(format!("{}{}", temp, strings.fahrenheit));
+        }
+        if let Some(condition) = &weather.condition {
+            weather_text.push(condition.clone());
+        }
+        if let Some(wind) = &weather.wind {
+            weather_text.push(format!("{} {}", strings.wind, wind));
+        }
+        parts.push(weather_text.join(", "));
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" "))
+    }
+}
+
+/// Where the game can be watched or heard, e.g. "TV: ESPN, YES (NYY) Radio: WFAN (NYY)".
+fn broadcasts_line(item_metadata: &ItemMetadata, strings: &Strings) -> Option<String> {
+    let team_name = |home_away: HomeAway| {
+        let team = match home_away {
+            HomeAway::Home => &item_metadata.teams.home.team,
+            HomeAway::Away => &item_metadata.teams.away.team,
+        };
+        team.short_name()
+    };
+    let names = |radio: bool| {
+        item_metadata
+            .broadcasts
+            .iter()
+            .filter(|broadcast| broadcast.broadcast_type.is_radio() == radio)
+            .map(|broadcast| {
+                let mut name = broadcast.name.clone();
+                if let (false, Some(home_away)) = (broadcast.is_national, broadcast.home_away) {
+                    name = format!("{} ({})", name, team_name(home_away));
+                }
+                if let Some(language) = broadcast
+                    .language
+                    .as_deref()
+                    .filter(|language| *language != "en")
+                {
+                    name = format!("{} [{}]", name, language);
+                }
+                name
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut parts = Vec::new();
+    for (label, radio) in &[(strings.tv, false), (strings.radio, true)] {
+        let names = names(*radio);
+        if !names.is_empty() {
+            parts.push(format!("{}: {}", label, names));
+        }
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" "))
+    }
+}
+
+/// Caption and attribution of a photo, e.g. "Photo: Judge homers (Rob Carr/Getty Images)".
+fn photo_line(photo: &Photo, strings: &Strings) -> Option<String> {
+    let caption = photo
+        .caption
+        .as_ref()
+        .or(photo.title.as_ref())
+        .filter(|caption| !caption.is_empty());
+    let credit = photo.credit.as_ref().filter(|credit| !credit.is_empty());
+    match (caption, credit) {
+        (Some(caption), Some(credit)) => {
+            Some(format!("{}: {} ({})", strings.photo, caption, credit))
+        }
+        (Some(caption), None) => Some(format!("{}: {}", strings.photo, caption)),
+        (None, Some(credit)) => Some(format!("{}: {}", strings.photo, credit)),
+        (None, None) => None,
+    }
+}
+
+/// Pitching decisions, e.g. "W: Gerrit Cole L: Chris Sale S: Aroldis Chapman".
+fn decisions_line(decisions: &Decisions, strings: &Strings) -> String {
+    [
+        (strings.win, &decisions.winner),
+        (strings.loss, &decisions.loser),
+        (strings.save, &decisions.save),
+    ]
+    .iter()
+    .filter_map(|(label, person)| {
+        person
+            .as_ref()
+            .map(|person| format!("{}: {}", label, person.full_name))
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
 #[tokio::main]
 pub async fn main() -> Result<(), String> {
     let background_path = Path::new(BACKGROUND_PATH);
@@ -75,19 +322,30 @@ pub async fn main() -> Result<(), String> {
     let background_texture = texture_creator.load_texture(background_path)?;
 
     // Initialize MLB client
-    let client = MlbClient::new();
+    let time_zone = time_zone()?;
+    let mut language = language()?;
+    let mut strings = strings::strings(language);
+    let mut client = MlbClient::builder()
+        .language(language)
+        .build()
+        .map_err(|err| err.to_string())?;
 
     // Initialize program state
     let network_state = Arc::new(Mutex::new(NetworkState::FetchingJson));
 
-    let mut date = time::date!(2018 - 06 - 10);
-    let task = networking::startup_procedure(date, client.clone(), network_state.clone());
-    tokio::spawn(task);
-
     // Initialize graphics state
     let mut gfx_state = GfxState::new(window_width, window_height, &texture_creator);
     let start_time = Instant::now();
 
+    let mut date = time::date!(2018 - 06 - 10);
+    let task = networking::startup_procedure(
+        date,
+        gfx_state.image_size(),
+        client.clone(),
+        network_state.clone(),
+    );
+    tokio::spawn(task);
+
     // Loading text rect
     let loading_height = window_height * 13 / 250;
     let loading_width = window_width / 5;
@@ -102,6 +360,33 @@ pub async fn main() -> Result<(), String> {
     let ttf_context = sdl2::ttf::init().map_err(|e| e.to_string())?;
 
     let mut networking_complete = false;
+    let mut error_text: Option<String> = None;
+
+    let mut screen = Screen::Carousel;
+    let standings_state = Arc::new(Mutex::new(Latest::default()));
+
+    let leaders_state: Arc<Mutex<Latest<_, Vec<_>>>> = Arc::new(Mutex::new(Latest::default()));
+    let mut leaders_category = 0;
+
+    let roster_state: Arc<Mutex<Latest<_, Vec<_>>>> = Arc::new(Mutex::new(Latest::default()));
+    let mut roster_team_name = String::new();
+    let mut roster_home = false;
+    let mut roster_selection = 0;
+
+    let player_state = Arc::new(Mutex::new(Latest::<u32, PlayerCard>::default()));
+
+    let mut article_index = 0;
+    let mut article_scroll = 0;
+    let mut article_max_scroll = 0;
+
+    let transactions_state: Arc<Mutex<Latest<_, Vec<_>>>> = Arc::new(Mutex::new(Latest::default()));
+    let mut transactions_requested = Instant::now();
+    let ticker_height = window_height / 36;
+    let ticker_font = ttf_context.load_font(Path::new(FONT_PATH), ticker_height as u16)?;
+    // Shared by loading and error text, secondary screens and scoreboard cards
+    let mut font = ttf_context.load_font(Path::new(FONT_PATH), loading_height as u16)?;
+    let header_font = ttf_context.load_font(Path::new(FONT_PATH), HEADER_TEXT_HEIGHT as u16)?;
+    let mut headshot_texture: Option<Texture> = None;
 
     'mainloop: loop {
         // Reset canvas
@@ -110,20 +395,44 @@ pub async fn main() -> Result<(), String> {
         // Render background texture
         canvas.copy(&background_texture, None, None)?;
 
+        // Follow the current baseball day with its transactions
+        {
+            let today = client.baseball_day().today();
+            let mut transactions = transactions_state.lock();
+            // This runs every frame, so failures are only retried after a while
+            let retry = matches!(transactions.state, FetchState::Error(_))
+                && transactions_requested.elapsed() >= TRANSACTIONS_RETRY;
+            if !transactions.is_requested(&today) || retry {
+                transactions.request(today);
+                transactions_requested = Instant::now();
+                let task = networking::fetch_transactions(
+                    today,
+                    client.clone(),
+                    transactions_state.clone(),
+                );
+                tokio::spawn(task);
+            }
+        }
+
         // Drain values from networking state
         if !networking_complete {
             match &mut *network_state.lock() {
                 NetworkState::Error(err) => {
-                    // TODO: Display error page
-                    println!("{}", err);
-                    break 'mainloop;
+                    // Keep running so that another date may be selected
+                    error_text = Some(error_message(err, strings));
+                    networking_complete = true;
                 }
                 NetworkState::FetchingJson => {
                     // Displaying loading page
-                    let font =
-                        ttf_context.load_font(Path::new(FONT_PATH), loading_height as u16)?;
-                    let loading_texture = get_loading_texture(&font, start_time, &texture_creator)?;
-                    canvas.copy(&loading_texture, None, Some(loading_rect))?;
+                    if screen == Screen::Carousel {
+                        let loading_texture = get_loading_texture(
+                            strings.fetching_games,
+                            &font,
+                            start_time,
+                            &texture_creator,
+                        )?;
+                        canvas.copy(&loading_texture, None, Some(loading_rect))?;
+                    }
                 }
                 NetworkState::FetchingImages(item_metadatas, image_paths) => {
                     // Initialize if required
@@ -139,43 +448,266 @@ pub async fn main() -> Result<(), String> {
             }
         }
 
+        // Display secondary screens
+        if screen != Screen::Carousel {
+            match screen {
+                Screen::Carousel => {}
+                Screen::Standings => match &standings_state.lock().state {
+                    FetchState::Done(standings) => standings::draw_standings(
+                        &mut canvas,
+                        standings,
+                        strings,
+                        &font,
+                        &texture_creator,
+                        window_width,
+                        window_height,
+                    )?,
+                    state => draw_fetch_status(
+                        &mut canvas,
+                        state,
+                        strings.fetching_standings,
+                        strings,
+                        &font,
+                        &texture_creator,
+                        loading_rect,
+                    )?,
+                },
+                Screen::Leaders => match &leaders_state.lock().state {
+                    FetchState::Done(leaders) => leaders::draw_leaders(
+                        &mut canvas,
+                        LeaderCategory::ALL[leaders_category],
+                        date.year(),
+                        leaders,
+                        strings,
+                        &font,
+                        &texture_creator,
+                        window_width,
+                        window_height,
+                    )?,
+                    state => draw_fetch_status(
+                        &mut canvas,
+                        state,
+                        strings.fetching_leaders,
+                        strings,
+                        &font,
+                        &texture_creator,
+                        loading_rect,
+                    )?,
+                },
+                Screen::Roster => match &roster_state.lock().state {
+                    FetchState::Done(roster) => roster::draw_roster(
+                        &mut canvas,
+                        &strings.roster.replace("{}", &roster_team_name),
+                        roster,
+                        roster_selection,
+                        &font,
+                        &texture_creator,
+                        window_width,
+                        window_height,
+                    )?,
+                    state => draw_fetch_status(
+                        &mut canvas,
+                        state,
+                        strings.fetching_roster,
+                        strings,
+                        &font,
+                        &texture_creator,
+                        loading_rect,
+                    )?,
+                },
+                Screen::PlayerCard => match &player_state.lock().state {
+                    FetchState::Done(card) => {
+                        // Load headshot once fetched
+                        if headshot_texture.is_none() {
+                            if let Some(headshot_path) = &card.headshot_path {
+                                headshot_texture =
+                                    texture_creator.load_texture(Path::new(headshot_path)).ok();
+                            }
+                        }
+                        roster::draw_player_card(
+                            &mut canvas,
+                            card,
+                            headshot_texture.as_ref(),
+                            strings,
+                            &font,
+                            &texture_creator,
+                            window_width,
+                            window_height,
+                        )?
+                    }
+                    state => draw_fetch_status(
+                        &mut canvas,
+                        state,
+                        strings.fetching_player,
+                        strings,
+                        &font,
+                        &texture_creator,
+                        loading_rect,
+                    )?,
+                },
+                Screen::Article => {
+                    let article = gfx_state
+                        .get_item_metadata(gfx_state.selection())
+                        .and_then(|item_metadata| item_metadata.articles().nth(article_index));
+                    if let Some(article) = article {
+                        article_max_scroll = article::draw_article(
+                            &mut canvas,
+                            article,
+                            article_scroll,
+                            strings,
+                            &mut font,
+                            &texture_creator,
+                            window_width,
+                            window_height,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        // Display error page
+        if let (Screen::Carousel, Some(error_text)) = (screen, &error_text) {
+            let error_texture = get_text_texture(error_text, &font, &texture_creator)?;
+            let (width, _) = font.size_of(error_text).map_err(|e| e.to_string())?;
+            let error_rect = Rect::new(
+                (window_width.saturating_sub(width)) as i32 / 2,
+                loading_rect.y(),
+                width.min(window_width),
+                loading_height,
+            );
+            canvas.copy(&error_texture, None, Some(error_rect))?;
+        }
+
         // Add textures
-        for i in 0..gfx_state.n_games() {
+        let n_visible = match screen {
+            Screen::Carousel => gfx_state.n_games(),
+            _ => 0,
+        };
+        for i in 0..n_visible {
             let rectangle = gfx_state.get_item_rectangle(i);
-            let texture = gfx_state.get_item_texture_mut(i).unwrap(); // This is safe after initialization
-
-            canvas.copy(texture, None, rectangle)?;
+            match (gfx_state.has_image(i), gfx_state.get_item_metadata(i)) {
+                (_, Some(item_metadata))
+                    if item_metadata.status.abstract_game_state == AbstractGameState::Preview =>
+                {
+                    draw_preview_card(
+                        &mut canvas,
+                        item_metadata,
+                        strings,
+                        &font,
+                        &texture_creator,
+                        rectangle,
+                    )?
+                }
+                (false, Some(item_metadata)) => draw_scoreboard_card(
+                    &mut canvas,
+                    item_metadata,
+                    &font,
+                    &texture_creator,
+                    rectangle,
+                )?,
+                _ => {
+                    let texture = gfx_state.get_item_texture_mut(i).unwrap(); // This is safe after initialization
+                    canvas.copy(texture, None, rectangle)?;
+                }
+            }
 
             if i == gfx_state.selection() {
                 // Add text
                 if let Some(item_metadata) = gfx_state.get_item_metadata(i) {
                     let (header_rect, mut blurb_rect) = gfx_state.get_selected_rectangles();
-                    let text_height = header_rect.height() as u16;
-                    let font = ttf_context.load_font(Path::new(FONT_PATH), text_height)?;
 
-                    // Add header
+                    // Add header, naming the teams for games without an article
+                    let headline = match item_metadata.article() {
+                        Some(article) => article.headline.clone(),
+                        None => format!(
+                            "{} @ {}",
+                            item_metadata.teams.away.team.name, item_metadata.teams.home.team.name
+                        ),
+                    };
                     let header_texture =
-                        get_text_texture(item_metadata.headline.as_ref(), &font, &texture_creator)?;
+                        get_text_texture(&headline, &header_font, &texture_creator)?;
 
                     canvas.copy(&header_texture, None, Some(header_rect))?;
 
-                    // Add blurb
-                    let lines = new_line_splitter(
-                        item_metadata.blurb.as_ref(),
-                        &font,
+                    // Add score, venue, broadcasts, blurb, photo credit and decisions
+                    let mut lines = new_line_splitter(
+                        &score_line(item_metadata, time_zone, strings),
+                        &header_font,
                         BLURB_TEXT_HEIGHT,
                         blurb_rect.width(),
                     )?;
+                    if let Some(venue_line) = venue_line(item_metadata, strings) {
+                        lines.extend(new_line_splitter(
+                            &venue_line,
+                            &header_font,
+                            BLURB_TEXT_HEIGHT,
+                            blurb_rect.width(),
+                        )?);
+                    }
+                    if let Some(broadcasts_line) = broadcasts_line(item_metadata, strings) {
+                        lines.extend(new_line_splitter(
+                            &broadcasts_line,
+                            &header_font,
+                            BLURB_TEXT_HEIGHT,
+                            blurb_rect.width(),
+                        )?);
+                    }
+                    if let Some(article) = item_metadata.article() {
+                        lines.extend(new_line_splitter(
+                            &article.blurb,
+                            &header_font,
+                            BLURB_TEXT_HEIGHT,
+                            blurb_rect.width(),
+                        )?);
+                        if let Some(photo_line) = photo_line(&article.photo, strings) {
+                            lines.extend(new_line_splitter(
+                                &photo_line,
+                                &header_font,
+                                BLURB_TEXT_HEIGHT,
+                                blurb_rect.width(),
+                            )?);
+                        }
+                    }
+
+                    if let Some(decisions) = &item_metadata.decisions {
+                        lines.extend(new_line_splitter(
+                            &decisions_line(decisions, strings),
+                            &header_font,
+                            BLURB_TEXT_HEIGHT,
+                            blurb_rect.width(),
+                        )?);
+                    }
                     for line in lines {
                         blurb_rect.set_y(blurb_rect.y() + blurb_rect.height() as i32);
 
-                        let blurb_texture = get_text_texture(&line, &font, &texture_creator)?;
+                        let blurb_texture =
+                            get_text_texture(&line, &header_font, &texture_creator)?;
                         canvas.copy(&blurb_texture, None, Some(blurb_rect))?;
                     }
                 }
             }
         }
 
+        // Display transactions ticker
+        if screen == Screen::Carousel {
+            if let FetchState::Done(transactions) = &transactions_state.lock().state {
+                let texts: Vec<String> = transactions
+                    .iter()
+                    .map(|transaction| transaction.description.clone())
+                    .filter(|description| !description.is_empty())
+                    .collect();
+                draw_ticker(
+                    &mut canvas,
+                    &texts,
+                    &ticker_font,
+                    &texture_creator,
+                    start_time.elapsed(),
+                    (window_height - ticker_height * 2) as i32,
+                    ticker_height,
+                )?;
+            }
+        }
+
         // Triger render
         canvas.present();
 
@@ -183,65 +715,275 @@ pub async fn main() -> Result<(), String> {
         for event in sdl_context.event_pump()?.poll_iter() {
             match event {
                 // Escape
-                Event::Quit { .. }
-                | Event::KeyDown {
+                Event::Quit { .. } => break 'mainloop,
+                Event::KeyDown {
                     keycode: Some(Keycode::Escape),
                     ..
-                } => break 'mainloop,
-                // Key right
+                } => match screen {
+                    Screen::Carousel => break 'mainloop,
+                    Screen::PlayerCard => screen = Screen::Roster,
+                    _ => screen = Screen::Carousel,
+                },
+                // Toggle standings
                 Event::KeyDown {
-                    keycode: Some(Keycode::Right),
+                    keycode: Some(Keycode::S),
                     ..
                 } => {
-                    if *network_state.lock() != NetworkState::FetchingJson {
-                        gfx_state.selection_right();
+                    if screen == Screen::Standings {
+                        screen = Screen::Carousel;
+                    } else if screen == Screen::Carousel {
+                        screen = Screen::Standings;
+                        let mut standings = standings_state.lock();
+                        if standings.needs_fetch(&date) {
+                            standings.request(date);
+                            let task = networking::fetch_standings(
+                                date,
+                                client.clone(),
+                                standings_state.clone(),
+                            );
+                            tokio::spawn(task);
+                        }
+                    }
+                }
+                // Toggle leaders, then cycle categories
+                Event::KeyDown {
+                    keycode: Some(keycode @ Keycode::L),
+                    ..
+                }
+                | Event::KeyDown {
+                    keycode: Some(keycode @ Keycode::Left),
+                    ..
+                }
+                | Event::KeyDown {
+                    keycode: Some(keycode @ Keycode::Right),
+                    ..
+                } if screen == Screen::Leaders
+                    || (screen == Screen::Carousel && keycode == Keycode::L) =>
+                {
+                    let n_categories = LeaderCategory::ALL.len();
+                    match keycode {
+                        Keycode::L if screen == Screen::Leaders => screen = Screen::Carousel,
+                        Keycode::L => screen = Screen::Leaders,
+                        Keycode::Left => {
+                            leaders_category = (leaders_category + n_categories - 1) % n_categories
+                        }
+                        _ => leaders_category = (leaders_category + 1) % n_categories,
+                    }
+
+                    let requested = (LeaderCategory::ALL[leaders_category], date.year());
+                    let mut leaders = leaders_state.lock();
+                    if screen == Screen::Leaders && leaders.needs_fetch(&requested) {
+                        leaders.request(requested);
+                        let task = networking::fetch_leaders(
+                            requested.0,
+                            requested.1,
+                            client.clone(),
+                            leaders_state.clone(),
+                        );
+                        tokio::spawn(task);
                     }
                 }
+                // Key right
+                Event::KeyDown {
+                    keycode: Some(Keycode::Right),
+                    ..
+                } if screen == Screen::Carousel && !network_state.lock().is_fetching_json() => {
+                    gfx_state.selection_right();
+                }
                 // Key left
                 Event::KeyDown {
                     keycode: Some(Keycode::Left),
                     ..
+                } if screen == Screen::Carousel && !network_state.lock().is_fetching_json() => {
+                    gfx_state.selection_left();
+                }
+                // Cycle between the selected game's recap, wrap and preview
+                Event::KeyDown {
+                    keycode: Some(Keycode::Tab),
+                    ..
+                } if screen == Screen::Article => {
+                    let n_articles = gfx_state
+                        .get_item_metadata(gfx_state.selection())
+                        .map_or(0, |item_metadata| item_metadata.articles().count());
+                    if n_articles > 0 {
+                        article_index = (article_index + 1) % n_articles;
+                        article_scroll = 0;
+                    }
+                }
+                // Open roster of the selected game's away team, then toggle home and away
+                Event::KeyDown {
+                    keycode: Some(keycode @ Keycode::R),
+                    ..
+                }
+                | Event::KeyDown {
+                    keycode: Some(keycode @ Keycode::Tab),
+                    ..
                 } => {
-                    if *network_state.lock() != NetworkState::FetchingJson {
-                        gfx_state.selection_left();
+                    let can_open = match keycode {
+                        Keycode::R => screen == Screen::Carousel,
+                        _ => screen == Screen::Roster,
+                    };
+                    let selected = gfx_state.get_item_metadata(gfx_state.selection());
+                    if let (true, Some(item_metadata)) = (can_open, selected) {
+                        roster_home = keycode == Keycode::Tab && !roster_home;
+                        let team = if roster_home {
+                            &item_metadata.teams.home.team
+                        } else {
+                            &item_metadata.teams.away.team
+                        };
+                        roster_team_name = team.name.clone();
+                        screen = Screen::Roster;
+
+                        let requested = RosterTeam { id: team.id, date };
+                        let mut roster = roster_state.lock();
+                        if roster.needs_fetch(&requested) {
+                            roster.request(requested);
+                            roster_selection = 0;
+                            let task = networking::fetch_roster(
+                                requested,
+                                client.clone(),
+                                roster_state.clone(),
+                            );
+                            tokio::spawn(task);
+                        }
                     }
                 }
+                // Open player card of the selected roster entry, or the selected game's articles
                 Event::KeyDown {
-                    keycode: Some(Keycode::Down),
+                    keycode: Some(Keycode::Return),
                     ..
                 } => {
-                    // TODO: Remove this condition by terminating prior future early using channel
-                    if networking_complete {
-                        gfx_state.reset();
-                        *network_state.lock() = NetworkState::FetchingJson;
-                        networking_complete = false;
-                        date = date.next_day();
-                        let task = networking::startup_procedure(
-                            date,
-                            client.clone(),
-                            network_state.clone(),
-                        );
-                        tokio::spawn(task);
+                    let has_article = gfx_state
+                        .get_item_metadata(gfx_state.selection())
+                        .and_then(|item_metadata| item_metadata.articles().next())
+                        .is_some();
+                    if screen == Screen::Carousel && has_article {
+                        screen = Screen::Article;
+                        article_index = 0;
+                        article_scroll = 0;
+                    } else if screen == Screen::Roster {
+                        if let FetchState::Done(roster) = &roster_state.lock().state {
+                            if let Some(entry) = roster.get(roster_selection) {
+                                screen = Screen::PlayerCard;
+                                headshot_texture = None;
+                                let mut player = player_state.lock();
+                                if player.needs_fetch(&entry.person.id) {
+                                    player.request(entry.person.id);
+                                    let task = networking::fetch_player_card(
+                                        entry.person.id,
+                                        client.clone(),
+                                        player_state.clone(),
+                                    );
+                                    tokio::spawn(task);
+                                }
+                            }
+                        }
                     }
                 }
+                // Scroll the article
+                Event::KeyDown {
+                    keycode: Some(Keycode::Down),
+                    ..
+                } if screen == Screen::Article => {
+                    article_scroll = (article_scroll + 1).min(article_max_scroll);
+                }
                 Event::KeyDown {
                     keycode: Some(Keycode::Up),
                     ..
-                } => {
-                    // TODO: Remove this condition by terminating prior future early using channel
-                    if networking_complete {
-                        gfx_state.reset();
-                        *network_state.lock() = NetworkState::FetchingJson;
-                        networking_complete = false;
-                        date = date.previous_day();
-                        let task = networking::startup_procedure(
-                            date,
-                            client.clone(),
-                            network_state.clone(),
-                        );
-                        tokio::spawn(task);
+                } if screen == Screen::Article => {
+                    article_scroll = article_scroll.min(article_max_scroll).saturating_sub(1);
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::PageDown),
+                    ..
+                } if screen == Screen::Article => {
+                    article_scroll = (article_scroll + ARTICLE_PAGE_LINES).min(article_max_scroll);
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::PageUp),
+                    ..
+                } if screen == Screen::Article => {
+                    article_scroll = article_scroll
+                        .min(article_max_scroll)
+                        .saturating_sub(ARTICLE_PAGE_LINES);
+                }
+                Event::KeyDown {
+                    keycode: Some(Keycode::Down),
+                    ..
+                } if screen == Screen::Roster => {
+                    if let FetchState::Done(roster) = &roster_state.lock().state {
+                        roster_selection =
+                            (roster_selection + 1).min(roster.len().saturating_sub(1));
                     }
                 }
+                Event::KeyDown {
+                    keycode: Some(Keycode::Up),
+                    ..
+                } if screen == Screen::Roster => {
+                    roster_selection = roster_selection.saturating_sub(1);
+                }
+                // Switch the language of articles and interface text, refetching the games
+                // TODO: Remove this condition by terminating prior future early using channel
+                Event::KeyDown {
+                    keycode: Some(Keycode::E),
+                    ..
+                } if screen == Screen::Carousel && networking_complete => {
+                    let index = Language::ALL
+                        .iter()
+                        .position(|other| *other == language)
+                        .unwrap_or_default();
+                    language = Language::ALL[(index + 1) % Language::ALL.len()];
+                    strings = strings::strings(language);
+                    client = client.with_language(language);
+
+                    gfx_state.reset();
+                    *network_state.lock() = NetworkState::FetchingJson;
+                    networking_complete = false;
+                    error_text = None;
+                    let task = networking::startup_procedure(
+                        date,
+                        gfx_state.image_size(),
+                        client.clone(),
+                        network_state.clone(),
+                    );
+                    tokio::spawn(task);
+                }
+                // TODO: Remove this condition by terminating prior future early using channel
+                Event::KeyDown {
+                    keycode: Some(Keycode::Down),
+                    ..
+                } if screen == Screen::Carousel && networking_complete => {
+                    gfx_state.reset();
+                    *network_state.lock() = NetworkState::FetchingJson;
+                    networking_complete = false;
+                    error_text = None;
+                    date = date.next_day();
+                    let task = networking::startup_procedure(
+                        date,
+                        gfx_state.image_size(),
+                        client.clone(),
+                        network_state.clone(),
+                    );
+                    tokio::spawn(task);
+                }
+                // TODO: Remove this condition by terminating prior future early using channel
+                Event::KeyDown {
+                    keycode: Some(Keycode::Up),
+                    ..
+                } if screen == Screen::Carousel && networking_complete => {
+                    gfx_state.reset();
+                    *network_state.lock() = NetworkState::FetchingJson;
+                    networking_complete = false;
+                    error_text = None;
+                    date = date.previous_day();
+                    let task = networking::startup_procedure(
+                        date,
+                        gfx_state.image_size(),
+                        client.clone(),
+                        network_state.clone(),
+                    );
+                    tokio::spawn(task);
+                }
                 _ => {}
             }
         }
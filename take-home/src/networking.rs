@@ -1,4 +1,9 @@
-use client::{types::ItemMetadata, MlbClient};
+use client::{
+    types::{
+        AspectRatio, ItemMetadata, LeagueLeader, PersonDetail, RosterEntry, Standings, Transaction,
+    },
+    Hydrate, LeaderCategory, MlbClient, MlbError, ScheduleQuery,
+};
 
 use futures::prelude::*;
 use parking_lot::Mutex;
@@ -7,46 +12,235 @@ use time::Date;
 use std::{fs, path::Path, sync::Arc};
 
 const THUMBNAIL_PATH: &str = "./assets/thumbnails/";
+const HEADSHOT_PATH: &str = "./assets/headshots/";
+const HEADSHOT_WIDTH: u32 = 213;
+const LEADERS_LIMIT: u32 = 10;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub enum NetworkState {
     FetchingJson,
     FetchingImages(Vec<ItemMetadata>, Vec<(usize, String)>),
-    Error(String),
+    Error(MlbError),
     Done(Vec<ItemMetadata>, Vec<(usize, String)>),
 }
 
-pub async fn startup_procedure(date: Date, client: MlbClient, state: Arc<Mutex<NetworkState>>) {
+impl NetworkState {
+    pub fn is_fetching_json(&self) -> bool {
+        matches!(self, NetworkState::FetchingJson)
+    }
+}
+
+/// State of a single request backing a screen.
+#[derive(Debug)]
+pub enum FetchState<T> {
+    Fetching,
+    Error(MlbError),
+    Done(T),
+}
+
+impl<T> From<Result<T, MlbError>> for FetchState<T> {
+    fn from(result: Result<T, MlbError>) -> Self {
+        match result {
+            Ok(value) => FetchState::Done(value),
+            Err(err) => FetchState::Error(err),
+        }
+    }
+}
+
+/// Fetch state of the latest request for a screen.
+///
+/// Requests are identified by a key, such as the date, so that a slow response to a superseded
+/// request cannot replace the response to the current one.
+#[derive(Debug)]
+pub struct Latest<K, T> {
+    key: Option<K>,
+    pub state: FetchState<T>,
+}
+
+impl<K: PartialEq, T> Default for Latest<K, T> {
+    fn default() -> Self {
+        Latest {
+            key: None,
+            state: FetchState::Fetching,
+        }
+    }
+}
+
+impl<K: PartialEq, T> Latest<K, T> {
+    /// Whether `key` is the latest request.
+    pub fn is_requested(&self, key: &K) -> bool {
+        self.key.as_ref() == Some(key)
+    }
+
+    /// Whether `key` has yet to be requested, or its request failed.
+    pub fn needs_fetch(&self, key: &K) -> bool {
+        !self.is_requested(key) || matches!(self.state, FetchState::Error(_))
+    }
+
+    /// Start a request for `key`, superseding any earlier one.
+    pub fn request(&mut self, key: K) {
+        self.key = Some(key);
+        self.state = FetchState::Fetching;
+    }
+
+    /// Store the response to the request for `key`, unless it has been superseded.
+    pub fn complete(&mut self, key: &K, result: Result<T, MlbError>) {
+        if self.key.as_ref() == Some(key) {
+            self.state = result.into();
+        }
+    }
+}
+
+pub async fn fetch_standings(
+    date: Date,
+    client: MlbClient,
+    state: Arc<Mutex<Latest<Date, Standings>>>,
+) {
+    let result = client.get_standings(date.year(), Some(&date)).await;
+    state.lock().complete(&date, result);
+}
+
+/// Leaders of a category in a season.
+pub type LeadersRequest = (LeaderCategory, i32);
+
+pub async fn fetch_leaders(
+    category: LeaderCategory,
+    season: i32,
+    client: MlbClient,
+    state: Arc<Mutex<Latest<LeadersRequest, Vec<LeagueLeader>>>>,
+) {
+    let result = client
+        .get_league_leaders(category, season, LEADERS_LIMIT)
+        .await;
+    state.lock().complete(&(category, season), result);
+}
+
+pub async fn fetch_transactions(
+    date: Date,
+    client: MlbClient,
+    state: Arc<Mutex<Latest<Date, Vec<Transaction>>>>,
+) {
+    let result = client.get_transactions(&date, &date, None).await;
+    state.lock().complete(&date, result);
+}
+
+/// Team whose roster is displayed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RosterTeam {
+    pub id: u32,
+    pub date: Date,
+}
+
+pub async fn fetch_roster(
+    team: RosterTeam,
+    client: MlbClient,
+    state: Arc<Mutex<Latest<RosterTeam, Vec<RosterEntry>>>>,
+) {
+    let result = client.get_roster(team.id, &team.date).await;
+    state.lock().complete(&team, result);
+}
+
+#[derive(Debug)]
+pub struct PlayerCard {
+    pub person: PersonDetail,
+    /// Missing if the headshot could not be fetched.
+    pub headshot_path: Option<String>,
+}
+
+pub async fn fetch_player_card(
+    person_id: u32,
+    client: MlbClient,
+    state: Arc<Mutex<Latest<u32, PlayerCard>>>,
+) {
+    // Create headshot path if missing
+    if !Path::new(HEADSHOT_PATH).exists() {
+        fs::create_dir_all(HEADSHOT_PATH).unwrap(); // Unrecoverable
+    }
+
+    let person = match client.get_person(person_id).await {
+        Ok(person) => person,
+        Err(err) => {
+            state.lock().complete(&person_id, Err(err));
+            return;
+        }
+    };
+
+    let file_path = format!("{}{}.png", HEADSHOT_PATH, person_id);
+    let headshot_path = match client.get_image(&person.headshot_url(HEADSHOT_WIDTH)).await {
+        Ok(raw) => tokio::fs::write(&file_path, raw)
+            .await
+            .ok()
+            .map(|()| file_path),
+        Err(_) => None,
+    };
+
+    let card = PlayerCard {
+        person,
+        headshot_path,
+    };
+    state.lock().complete(&person_id, Ok(card));
+}
+
+/// Schedule hydrations displayed by the carousel.
+fn schedule_query(date: Date) -> ScheduleQuery {
+    let hydrate = Hydrate::new()
+        .team()
+        .editorial()
+        .preview()
+        .wrap()
+        .decisions()
+        .probable_pitcher_stats(date.year())
+        .venue()
+        .weather()
+        .broadcasts();
+    ScheduleQuery::new().hydrate(hydrate)
+}
+
+/// Fetch the games of a date and their photos, choosing cuts of at least `image_size`.
+pub async fn startup_procedure(
+    date: Date,
+    image_size: (u32, u32),
+    client: MlbClient,
+    state: Arc<Mutex<NetworkState>>,
+) {
     // Create thumbnail path if missing
     if !Path::new(THUMBNAIL_PATH).exists() {
         fs::create_dir_all(THUMBNAIL_PATH).unwrap(); // Unrecoverable
     }
 
-    match client.get_schedule_via_date(&date).await {
+    match client
+        .get_schedule_via_date(&date, &schedule_query(date))
+        .await
+    {
         Err(err) => {
-            // Reached error state - no item_metadata data found
-            *state.lock() = NetworkState::Error(err.to_string());
+            // Reached error state - request failed or no games on date
+            *state.lock() = NetworkState::Error(err);
         }
         Ok(schedule) => {
             // Collect item_metadata data
-            let mut item_metadata_data = schedule.into_item_metadata_data();
-            let item_metadatas = match item_metadata_data.pop() {
+            let mut item_metadata_data = schedule.into_item_metadata_data(client.language());
+            let item_metadatas = match item_metadata_data.remove(&date) {
                 Some(some) => some,
                 None => {
-                    // Reached error state - no item_metadata data found
-                    *state.lock() = NetworkState::Error("Missing item_metadata data".to_string());
+                    // Reached error state - no item_metadata data found for the date
+                    *state.lock() = NetworkState::Error(MlbError::NoData { date });
                     return;
                 }
             };
 
             // Collect image URLs
+            let (min_width, min_height) = image_size;
             let image_urls: Vec<(u32, Option<String>)> = item_metadatas
                 .iter()
                 .map(move |item_metadata| {
-                    (
-                        item_metadata.id,
-                        item_metadata.photos.get("684x385").cloned(),
-                    )
+                    // Prefer cuts fitting the carousel, but show any cut over none
+                    let cut = item_metadata.article().and_then(|article| {
+                        let photo = &article.photo;
+                        photo
+                            .best_cut(min_width, min_height, Some(AspectRatio::WIDESCREEN))
+                            .or_else(|| photo.best_cut(min_width, min_height, None))
+                    });
+                    (item_metadata.id, cut.map(|cut| cut.src.clone()))
                 })
                 .collect();
 
@@ -61,7 +255,7 @@ pub async fn startup_procedure(date: Date, client: MlbClient, state: Arc<Mutex<N
                     async move {
                         // TODO: Check for cached image
                         if let Some(url) = url {
-                            // Game had an editorial entry
+                            // Game had an editorial photo
                             let raw = client_inner
                                 .get_image(url)
                                 .await
@@ -85,6 +279,7 @@ pub async fn startup_procedure(date: Date, client: MlbClient, state: Arc<Mutex<N
             image_fetching.await;
 
             // TODO: Speed this up
+            // Leave the state untouched if it has been reset in the meantime
             let state_lock = &mut *state.lock();
             if let NetworkState::FetchingImages(item_metadata, image_paths) = state_lock {
                 let mut new_meta = Vec::new();
@@ -92,8 +287,6 @@ pub async fn startup_procedure(date: Date, client: MlbClient, state: Arc<Mutex<N
                 let mut new_paths = Vec::new();
                 new_paths.append(image_paths);
                 *state_lock = NetworkState::Done(new_meta, new_paths);
-            } else {
-                *state_lock = NetworkState::Error("unexpected state transition".to_string());
             };
         }
     }
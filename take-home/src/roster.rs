@@ -0,0 +1,192 @@
+use client::types::{RosterEntry, StatLine};
+
+use sdl2::{
+    rect::Rect,
+    render::{Canvas, Texture, TextureCreator},
+    ttf::Font,
+    video::{Window, WindowContext},
+};
+
+use crate::{format::pitching_line, graphics::draw_text, networking::PlayerCard, strings::Strings};
+
+/// Batting side and throwing hand, e.g. "L/R".
+fn handedness(bats: Option<&str>, throws: Option<&str>) -> String {
+    format!("{}/{}", bats.unwrap_or("-"), throws.unwrap_or("-"))
+}
+
+fn roster_row(entry: &RosterEntry) -> String {
+    let person = &entry.person;
+    format!(
+        "#{:<3} {:<3} {:<24} {}",
+        entry.jersey_number.as_deref().unwrap_or("-"),
+        entry.position.abbreviation,
+        person.full_name,
+        handedness(
+            person.bat_side.as_ref().map(|side| side.code.as_str()),
+            person.pitch_hand.as_ref().map(|hand| hand.code.as_str()),
+        )
+    )
+}
+
+/// Draw a scrolling roster list with the selected player highlighted.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_roster<'ttf>(
+    canvas: &mut Canvas<Window>,
+    title: &str,
+    roster: &[RosterEntry],
+    selection: usize,
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    window_width: u32,
+    window_height: u32,
+) -> Result<(), String> {
+    let padding = window_width / 40;
+    let line_height = window_height / 30;
+    let x = padding as i32;
+    let mut y = padding as i32;
+
+    draw_text(
+        canvas,
+        title,
+        font,
+        texture_creator,
+        x,
+        y,
+        line_height * 3 / 2,
+    )?;
+    y += (line_height * 2) as i32;
+
+    // Keep the selection in view
+    let n_rows = ((window_height - 4 * padding) / line_height)
+        .saturating_sub(2)
+        .max(1) as usize;
+    let first = selection.saturating_sub(n_rows / 2);
+    for (i, entry) in roster.iter().enumerate().skip(first).take(n_rows) {
+        let marker = if i == selection { ">" } else { " " };
+        let row = format!("{} {}", marker, roster_row(entry));
+        draw_text(canvas, &row, font, texture_creator, x, y, line_height)?;
+        y += line_height as i32;
+    }
+    Ok(())
+}
+
+/// Draw a player's headshot alongside their profile and season statistics.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_player_card<'ttf>(
+    canvas: &mut Canvas<Window>,
+    card: &PlayerCard,
+    headshot: Option<&Texture>,
+    strings: &Strings,
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    window_width: u32,
+    window_height: u32,
+) -> Result<(), String> {
+    let person = &card.person;
+    let padding = window_width / 40;
+    let line_height = window_height / 30;
+    let headshot_width = window_width / 6;
+    let headshot_height = headshot_width * 4 / 3;
+
+    if let Some(headshot) = headshot {
+        canvas.copy(
+            headshot,
+            None,
+            Rect::new(
+                padding as i32,
+                padding as i32,
+                headshot_width,
+                headshot_height,
+            ),
+        )?;
+    }
+
+    let mut lines = vec![format!(
+        "#{} | {} | {} {}",
+        person.primary_number.as_deref().unwrap_or("-"),
+        person
+            .primary_position
+            .as_ref()
+            .map(|position| position.name.as_str())
+            .unwrap_or("-"),
+        strings.bats_throws,
+        handedness(
+            person.bat_side.as_ref().map(|side| side.code.as_str()),
+            person.pitch_hand.as_ref().map(|hand| hand.code.as_str()),
+        )
+    )];
+    if let Some(team) = &person.current_team {
+        lines.push(team.name.clone());
+    }
+    if let (Some(height), Some(weight)) = (&person.height, person.weight) {
+        lines.push(format!("{} {} {}", height, weight, strings.pounds));
+    }
+    if let Some(birth_date) = person.birth_date {
+        let mut born = format!("{} {}", strings.born, birth_date.format("%Y-%m-%d"));
+        if let Some(birth_place) = person.birth_place() {
+            born = format!("{} {} {}", born, strings.born_in, birth_place);
+        }
+        if let Some(age) = person.current_age {
+            born = format!("{} ({} {})", born, strings.age, age);
+        }
+        lines.push(born);
+    }
+    if let Some(debut) = person.mlb_debut_date {
+        lines.push(format!(
+            "{} {}",
+            strings.mlb_debut,
+            debut.format("%Y-%m-%d")
+        ));
+    }
+    lines.push(String::new());
+
+    for stats in &person.stats {
+        for split in &stats.splits {
+            let season = split.season.as_deref().unwrap_or(strings.career);
+            match &split.stat {
+                StatLine::Hitting(stat) => {
+                    let [avg, home_runs, rbi, ops] = strings.hitting_columns;
+                    lines.push(format!(
+                        "{}: {} {} {} {} {} {} {} {}",
+                        season,
+                        stat.avg,
+                        avg,
+                        stat.home_runs,
+                        home_runs,
+                        stat.rbi,
+                        rbi,
+                        stat.ops,
+                        ops
+                    ))
+                }
+                StatLine::Pitching(stat) => lines.push(pitching_line(season, stat, strings)),
+                StatLine::Fielding(stat) => {
+                    let [fielding, errors, innings] = strings.fielding_columns;
+                    lines.push(format!(
+                        "{}: {} {} {} {} {} {}",
+                        season, stat.fielding, fielding, stat.errors, errors, stat.innings, innings
+                    ))
+                }
+                StatLine::Other(_) => {}
+            }
+        }
+    }
+
+    let x = (padding * 2 + headshot_width) as i32;
+    let mut y = padding as i32;
+    draw_text(
+        canvas,
+        &person.full_name,
+        font,
+        texture_creator,
+        x,
+        y,
+        line_height * 3 / 2,
+    )?;
+    y += (line_height * 2) as i32;
+    for line in lines {
+        draw_text(canvas, &line, font, texture_creator, x, y, line_height)?;
+        y += line_height as i32;
+    }
+    Ok(())
+}
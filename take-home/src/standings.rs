@@ -0,0 +1,139 @@
+use client::types::{DivisionStandings, Standings, TeamRecord};
+
+use sdl2::{
+    render::{Canvas, TextureCreator},
+    ttf::Font,
+    video::{Window, WindowContext},
+};
+
+use crate::{graphics::draw_text, strings::Strings};
+
+/// Column headings, aligned with [`team_row`].
+fn header_row(strings: &Strings) -> String {
+    let [team, wins, losses, percentage, games_back, streak, last_ten, differential] =
+        strings.standings_columns;
+    format!(
+        "{:<5}{:>4}{:>4}{:>6}{:>6}{:>5}{:>5}{:>6}",
+        team, wins, losses, percentage, games_back, streak, last_ten, differential
+    )
+}
+
+fn division_title(division: &DivisionStandings, strings: &Strings) -> String {
+    division
+        .division
+        .as_ref()
+        .and_then(|division| {
+            division
+                .name_short
+                .clone()
+                .or_else(|| division.name.clone())
+        })
+        .unwrap_or_else(|| {
+            strings
+                .league
+                .replace("{}", &division.league.id.to_string())
+        })
+}
+
+fn team_row(record: &TeamRecord) -> String {
+    let name = record.team.short_name();
+    let streak = record
+        .streak
+        .as_ref()
+        .map(|streak| streak.streak_code.as_str())
+        .unwrap_or("-");
+    let last_ten = record
+        .last_ten()
+        .map(|last_ten| format!("{}-{}", last_ten.wins, last_ten.losses))
+        .unwrap_or_else(|| "-".to_string());
+    format!(
+        "{:<5}{:>4}{:>4}{:>6}{:>6}{:>5}{:>5}{:>+6}",
+        name,
+        record.wins,
+        record.losses,
+        record.winning_percentage,
+        record.games_back,
+        streak,
+        last_ten,
+        record.run_differential
+    )
+}
+
+/// Draw division standings in a grid, one row of divisions per league.
+pub fn draw_standings<'ttf>(
+    canvas: &mut Canvas<Window>,
+    standings: &Standings,
+    strings: &Strings,
+    font: &Font<'ttf, 'static>,
+    texture_creator: &TextureCreator<WindowContext>,
+    window_width: u32,
+    window_height: u32,
+) -> Result<(), String> {
+    let padding = window_width / 40;
+    let mut league_ids: Vec<u32> = standings
+        .records
+        .iter()
+        .map(|division| division.league.id)
+        .collect();
+    league_ids.sort_unstable();
+    league_ids.dedup();
+
+    let n_columns = standings
+        .records
+        .iter()
+        .filter(|division| Some(&division.league.id) == league_ids.first())
+        .count()
+        .max(1) as u32;
+    let column_width = (window_width - padding) / n_columns;
+    let row_height = (window_height - padding) / (league_ids.len().max(1) as u32);
+
+    // Monospace glyphs are roughly 3/5 as wide as they are tall
+    let header_row = header_row(strings);
+    let line_height = (window_height / 36)
+        .min(column_width.saturating_sub(padding) * 5 / 3 / header_row.chars().count() as u32);
+
+    for (row, league_id) in league_ids.iter().enumerate() {
+        let divisions = standings
+            .records
+            .iter()
+            .filter(|division| division.league.id == *league_id);
+        for (column, division) in divisions.enumerate() {
+            let x = (padding + column as u32 * column_width) as i32;
+            let mut y = (padding + row as u32 * row_height) as i32;
+
+            draw_text(
+                canvas,
+                &division_title(division, strings),
+                font,
+                texture_creator,
+                x,
+                y,
+                line_height * 3 / 2,
+            )?;
+            y += (line_height * 2) as i32;
+
+            draw_text(
+                canvas,
+                &header_row,
+                font,
+                texture_creator,
+                x,
+                y,
+                line_height,
+            )?;
+            for record in &division.team_records {
+                y += line_height as i32;
+                draw_text(
+                    canvas,
+                    &team_row(record),
+                    font,
+                    texture_creator,
+                    x,
+                    y,
+                    line_height,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
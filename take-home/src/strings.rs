@@ -0,0 +1,151 @@
+use client::Language;
+
+/// Interface text in one language.
+///
+/// Templates containing `{}` have it replaced by a value, as word order differs by language.
+pub struct Strings {
+    pub fetching_games: &'static str,
+    pub fetching_standings: &'static str,
+    pub fetching_leaders: &'static str,
+    pub fetching_roster: &'static str,
+    pub fetching_player: &'static str,
+    /// Followed by the date.
+    pub no_games_on: &'static str,
+    /// Followed by the status code.
+    pub api_unavailable: &'static str,
+    pub api_unreachable: &'static str,
+    pub api_unexpected: &'static str,
+    pub player_not_found: &'static str,
+    /// Counts games of a doubleheader, e.g. "game 2".
+    pub game: &'static str,
+    /// Probable pitcher not yet announced.
+    pub to_be_decided: &'static str,
+    /// Template taking the roof type, e.g. "Retractable".
+    pub roof: &'static str,
+    /// Unit following the temperature.
+    pub fahrenheit: &'static str,
+    pub wind: &'static str,
+    pub tv: &'static str,
+    pub radio: &'static str,
+    pub photo: &'static str,
+    pub win: &'static str,
+    pub loss: &'static str,
+    pub save: &'static str,
+    /// Template taking the team name.
+    pub roster: &'static str,
+    /// Template taking the season.
+    pub league_leaders: &'static str,
+    /// Template taking the league ID, for divisions without a name.
+    pub league: &'static str,
+    /// Standings columns: team, wins, losses, winning percentage, games back, streak, last ten
+    /// games and run differential.
+    pub standings_columns: [&'static str; 8],
+    /// Followed by batting side and throwing hand, e.g. "L/R".
+    pub bats_throws: &'static str,
+    /// Unit following the weight.
+    pub pounds: &'static str,
+    /// Followed by the birth date.
+    pub born: &'static str,
+    /// Precedes the birth place.
+    pub born_in: &'static str,
+    pub age: &'static str,
+    /// Followed by the debut date.
+    pub mlb_debut: &'static str,
+    /// Labels totals of a player's whole career.
+    pub career: &'static str,
+    /// Hitting columns: average, home runs, runs batted in and on-base plus slugging.
+    pub hitting_columns: [&'static str; 4],
+    /// Pitching columns: earned run average, innings pitched, strikeouts and walks plus hits per
+    /// inning pitched.
+    pub pitching_columns: [&'static str; 4],
+    /// Fielding columns: fielding percentage, errors and innings.
+    pub fielding_columns: [&'static str; 3],
+    /// Stands in for an image within an article.
+    pub image: &'static str,
+}
+
+const ENGLISH: Strings = Strings {
+    fetching_games: "Fetching Games",
+    fetching_standings: "Fetching Standings...",
+    fetching_leaders: "Fetching Leaders...",
+    fetching_roster: "Fetching Roster...",
+    fetching_player: "Fetching Player...",
+    no_games_on: "No games on",
+    api_unavailable: "Stats API unavailable",
+    api_unreachable: "Unable to reach stats API",
+    api_unexpected: "Unexpected stats API response",
+    player_not_found: "Player not found",
+    game: "game",
+    to_be_decided: "TBD",
+    roof: "{} roof",
+    fahrenheit: "°F",
+    wind: "wind",
+    tv: "TV",
+    radio: "Radio",
+    photo: "Photo",
+    win: "W",
+    loss: "L",
+    save: "S",
+    roster: "{} Roster",
+    league_leaders: "{} League Leaders",
+    league: "League {}",
+    standings_columns: ["TEAM", "W", "L", "PCT", "GB", "STRK", "L10", "DIFF"],
+    bats_throws: "B/T:",
+    pounds: "lb",
+    born: "Born:",
+    born_in: "in",
+    age: "age",
+    mlb_debut: "MLB debut:",
+    career: "Career",
+    hitting_columns: ["AVG", "HR", "RBI", "OPS"],
+    pitching_columns: ["ERA", "IP", "K", "WHIP"],
+    fielding_columns: ["FLD%", "E", "INN"],
+    image: "Image",
+};
+
+const SPANISH: Strings = Strings {
+    fetching_games: "Cargando Juegos",
+    fetching_standings: "Cargando Posiciones...",
+    fetching_leaders: "Cargando Líderes...",
+    fetching_roster: "Cargando Plantilla...",
+    fetching_player: "Cargando Jugador...",
+    no_games_on: "No hay juegos el",
+    api_unavailable: "API de estadísticas no disponible",
+    api_unreachable: "No se puede conectar a la API de estadísticas",
+    api_unexpected: "Respuesta inesperada de la API de estadísticas",
+    player_not_found: "Jugador no encontrado",
+    game: "juego",
+    to_be_decided: "Por anunciar",
+    roof: "techo {}",
+    fahrenheit: "°F",
+    wind: "viento",
+    tv: "TV",
+    radio: "Radio",
+    photo: "Foto",
+    win: "G",
+    loss: "P",
+    save: "SV",
+    roster: "Plantilla de {}",
+    league_leaders: "Líderes de la Liga {}",
+    league: "Liga {}",
+    standings_columns: ["EQ", "G", "P", "PCT", "JD", "RACH", "U10", "DIF"],
+    bats_throws: "B/L:",
+    pounds: "lb",
+    born: "Nacido:",
+    born_in: "en",
+    age: "edad",
+    mlb_debut: "Debut en MLB:",
+    career: "Carrera",
+    hitting_columns: ["PRO", "JR", "CI", "OPS"],
+    pitching_columns: ["EFE", "EL", "K", "WHIP"],
+    fielding_columns: ["PCT.F", "E", "ENT"],
+    image: "Imagen",
+};
+
+/// Interface text for a language.
+pub fn strings(language: Language) -> &'static Strings {
+    match language {
+        Language::English => &ENGLISH,
+        Language::Spanish => &SPANISH,
+    }
+}